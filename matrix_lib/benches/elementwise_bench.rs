@@ -0,0 +1,72 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    matrix::{self, Matrix},
+    matrix_functions::add,
+};
+use std::time::{Duration, Instant};
+
+/// the best of several runs
+fn measure<T, F: FnMut() -> T>(runs: usize, mut f: F) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn report(name: &str, closure: Duration, kernel: Duration) {
+    println!(
+        "{:>10} {:>12.1} {:>12.1} {:>7.1}x",
+        name,
+        closure.as_secs_f64() * 1e6,
+        kernel.as_secs_f64() * 1e6,
+        closure.as_secs_f64() / kernel.as_secs_f64(),
+    );
+}
+
+fn main() {
+    // the first size fits in cache, the second one is limited by memory bandwidth
+    for size in [256, 2048] {
+        run(size);
+    }
+}
+
+fn run(size: usize) {
+    let runs = 5 * (2048 / size).pow(2);
+    let a = Matrix::random_seeded(size, size, 1);
+    let b = Matrix::random_seeded(size, size, 2);
+    println!("\n{size}x{size}");
+    println!("{:>10} {:>12} {:>12} {:>8}", "operation", "closure, us", "kernel, us", "speedup");
+
+    report(
+        "add",
+        measure(runs, || matrix::map(&a, &b, |x, y| x + y).unwrap()),
+        measure(runs, || add(&a, &b).unwrap()),
+    );
+
+    let mut m = a.clone();
+    report(
+        "add_assign",
+        measure(runs, || m.modify_other(&b, |x, y| x + y).unwrap()),
+        measure(runs, || m.add_assign(&b).unwrap()),
+    );
+    report(
+        "mul_scalar",
+        measure(runs, || m.modify(|x| x * 1.0001)),
+        measure(runs, || m.mul_assign(1.0001)),
+    );
+    report(
+        "sum",
+        measure(runs, || a.as_slice().iter().fold(0.0, |acc, x| acc + x)),
+        measure(runs, || a.sum()),
+    );
+    report(
+        "dot",
+        measure(runs, || a.as_slice().iter().zip(b.as_slice()).fold(0.0, |acc, (x, y)| acc + x * y)),
+        measure(runs, || a.dot(&b).unwrap()),
+    );
+}
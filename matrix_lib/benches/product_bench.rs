@@ -0,0 +1,44 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    matrix::Matrix,
+    matrix_functions::product,
+};
+use std::time::{Duration, Instant};
+
+/// the straightforward i-j-k product, kept as a baseline
+fn naive_product(first: &Matrix, second: &Matrix) -> Matrix {
+    Matrix::new(first.rows(), second.cols(), |i, j| {
+        (0..first.cols()).map(|k| first[i][k] * second[k][j]).sum()
+    })
+}
+
+/// the best of several runs
+fn measure<F: FnMut() -> Matrix>(runs: usize, mut f: F) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    let mode = if cfg!(feature = "parallel") { "blocked, parallel" } else { "blocked" };
+    println!("{:>6} {:>12} {:>20} {:>8}", "size", "naive, ms", format!("{mode}, ms"), "speedup");
+    for size in [512, 768, 1024] {
+        let a = Matrix::random_seeded(size, size, 1);
+        let b = Matrix::random_seeded(size, size, 2);
+        let naive = measure(2, || naive_product(&a, &b));
+        let blocked = measure(3, || product(&a, &b).unwrap());
+        println!(
+            "{:>6} {:>12.1} {:>20.1} {:>7.1}x",
+            size,
+            naive.as_secs_f64() * 1e3,
+            blocked.as_secs_f64() * 1e3,
+            naive.as_secs_f64() / blocked.as_secs_f64(),
+        );
+    }
+}
@@ -15,16 +15,14 @@ impl Dimensions {
         Self::new(dim, dim)
     }
 
-    pub fn from_vector<T>(vec: &Vec<Vec<T>>) -> MathResult<Self> {
+    pub fn from_vector<T>(vec: &[Vec<T>]) -> MathResult<Self> {
         let rows = vec.len();
         if rows == 0 {
             return Ok(Self::new(0, 0));
         }
         let cols = vec[0].len();
-        for i in 1..rows {
-            if vec[i].len() != cols {
-                return Err(MathError::IncorrectVectorDimensions);
-            }
+        if vec.iter().skip(1).any(|row| row.len() != cols) {
+            return Err(MathError::IncorrectVectorDimensions);
         }
         Ok(Self::new(rows, cols))
     }
@@ -53,6 +51,19 @@ impl Dimensions {
     pub fn size(&self) -> usize {
         self.rows * self.cols
     }
+
+    /// common dimensions by NumPy rules: sizes must be equal or one of them must be 1
+    pub fn broadcast(&self, other: &Dimensions) -> Option<Dimensions> {
+        fn common(a: usize, b: usize) -> Option<usize> {
+            match (a, b) {
+                _ if a == b => Some(a),
+                (1, _) => Some(b),
+                (_, 1) => Some(a),
+                _ => None,
+            }
+        }
+        Some(Self::new(common(self.rows, other.rows)?, common(self.cols, other.cols)?))
+    }
 }
 
 impl PartialEq for Dimensions {
@@ -1,11 +1,32 @@
-use std::fmt;
+use std::{
+    error::Error,
+    fmt,
+    io,
+    num::{ParseFloatError, ParseIntError},
+};
 use super::dimensions::Dimensions;
 
-#[derive(PartialEq)]
 pub enum MathError {
     IncorrectVectorDimensions,
     IncorrectPosition(usize, usize),
+    IncorrectBlock(usize, usize, Dimensions, Dimensions),
     IncorrectMatricesDimensions(String, Dimensions, Dimensions),
+    IncompatibleBroadcast(String, Dimensions, Dimensions),
+    NotSquareMatrix(String, Dimensions),
+    SingularMatrix(String),
+    RankDeficientMatrix(String, usize),
+    NotSymmetricMatrix(String),
+    NotPositiveDefiniteMatrix(String),
+    NotConverged(String, usize),
+    EmptyMatrix(String),
+    /// NaN or infinity detected by the operation
+    NotFinite(String),
+    /// parameter name and its rejected value
+    InvalidParameter(String, f64),
+    Io(io::Error),
+    Parse(String),
+    /// error raised by the network layer with the given index
+    Layer(usize, Box<MathError>),
 }
 
 impl MathError {
@@ -15,8 +36,70 @@ impl MathError {
                 "Initializing 2d vector must have the same column number in each row".to_string(),
             MathError::IncorrectPosition(row, col) => 
                 format!("Row {} and/or col {} are/is out of bounds", row, col),
+            MathError::IncorrectBlock(row, col, block, dim) =>
+                format!("Block {:?} at {}:{} is out of bounds of matrix with dimensions {:?}", block, row, col, dim),
             MathError::IncorrectMatricesDimensions(op_name, dim1, dim2) => 
                 format!("Can't perform operation '{}' with matrices with dimensions {:?} and {:?}", op_name, dim1, dim2),
+            MathError::IncompatibleBroadcast(op_name, dim1, dim2) =>
+                format!("Can't broadcast dimensions {:?} and {:?} in operation '{}', each pair of sizes must be equal or contain 1", dim1, dim2, op_name),
+            MathError::NotSquareMatrix(op_name, dim) =>
+                format!("Operation '{}' requires square matrix but dimensions are {:?}", op_name, dim),
+            MathError::SingularMatrix(op_name) =>
+                format!("Can't perform operation '{}' with singular matrix", op_name),
+            MathError::RankDeficientMatrix(op_name, rank) =>
+                format!("Can't perform operation '{}' with rank deficient matrix, rank is {}", op_name, rank),
+            MathError::NotSymmetricMatrix(op_name) =>
+                format!("Operation '{}' requires symmetric matrix", op_name),
+            MathError::NotPositiveDefiniteMatrix(op_name) =>
+                format!("Operation '{}' requires positive-definite matrix", op_name),
+            MathError::NotConverged(op_name, iterations) =>
+                format!("Operation '{}' didn't converge after {} iterations", op_name, iterations),
+            MathError::EmptyMatrix(op_name) =>
+                format!("Operation '{}' requires at least one element", op_name),
+            MathError::NotFinite(op_name) =>
+                format!("Operation '{}' produced NaN or infinite value", op_name),
+            MathError::InvalidParameter(name, value) =>
+                format!("Parameter '{}' has invalid value {}", name, value),
+            MathError::Io(error) =>
+                format!("IO error: {}", error),
+            MathError::Parse(message) =>
+                format!("Can't parse number: {}", message),
+            MathError::Layer(index, error) =>
+                format!("Layer {}: {}", index, error),
+        }
+    }
+}
+
+impl MathError {
+    /// attaches index of the network layer where the error happened
+    pub fn in_layer(self, index: usize) -> Self {
+        MathError::Layer(index, Box::new(self))
+    }
+}
+
+/// IO errors are equal if they have the same kind and message, parameter values are compared bitwise so NaN equals NaN
+impl PartialEq for MathError {
+    fn eq(&self, other: &Self) -> bool {
+        use MathError::*;
+        match (self, other) {
+            (IncorrectVectorDimensions, IncorrectVectorDimensions) => true,
+            (IncorrectPosition(r1, c1), IncorrectPosition(r2, c2)) => r1 == r2 && c1 == c2,
+            (IncorrectBlock(r1, c1, b1, d1), IncorrectBlock(r2, c2, b2, d2)) => r1 == r2 && c1 == c2 && b1 == b2 && d1 == d2,
+            (IncorrectMatricesDimensions(o1, a1, b1), IncorrectMatricesDimensions(o2, a2, b2)) => o1 == o2 && a1 == a2 && b1 == b2,
+            (IncompatibleBroadcast(o1, a1, b1), IncompatibleBroadcast(o2, a2, b2)) => o1 == o2 && a1 == a2 && b1 == b2,
+            (NotSquareMatrix(o1, d1), NotSquareMatrix(o2, d2)) => o1 == o2 && d1 == d2,
+            (SingularMatrix(o1), SingularMatrix(o2)) => o1 == o2,
+            (RankDeficientMatrix(o1, r1), RankDeficientMatrix(o2, r2)) => o1 == o2 && r1 == r2,
+            (NotSymmetricMatrix(o1), NotSymmetricMatrix(o2)) => o1 == o2,
+            (NotPositiveDefiniteMatrix(o1), NotPositiveDefiniteMatrix(o2)) => o1 == o2,
+            (NotConverged(o1, i1), NotConverged(o2, i2)) => o1 == o2 && i1 == i2,
+            (EmptyMatrix(o1), EmptyMatrix(o2)) => o1 == o2,
+            (NotFinite(o1), NotFinite(o2)) => o1 == o2,
+            (InvalidParameter(n1, v1), InvalidParameter(n2, v2)) => n1 == n2 && v1.to_bits() == v2.to_bits(),
+            (Io(e1), Io(e2)) => e1.kind() == e2.kind() && e1.to_string() == e2.to_string(),
+            (Parse(m1), Parse(m2)) => m1 == m2,
+            (Layer(i1, e1), Layer(i2, e2)) => i1 == i2 && e1 == e2,
+            _ => false,
         }
     }
 }
@@ -27,4 +110,38 @@ impl fmt::Debug for MathError {
     }
 }
 
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for MathError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MathError::Io(error) => Some(error),
+            MathError::Layer(_, error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MathError {
+    fn from(error: io::Error) -> Self {
+        MathError::Io(error)
+    }
+}
+
+impl From<ParseFloatError> for MathError {
+    fn from(error: ParseFloatError) -> Self {
+        MathError::Parse(error.to_string())
+    }
+}
+
+impl From<ParseIntError> for MathError {
+    fn from(error: ParseIntError) -> Self {
+        MathError::Parse(error.to_string())
+    }
+}
+
 pub type MathResult<T> = std::result::Result<T, MathError>;
@@ -3,7 +3,15 @@
 pub mod errors;
 pub mod dimensions;
 pub mod matrix;
+pub mod matrix_view;
+pub mod scalar;
+pub mod simd;
 pub mod matrix_debug;
 pub mod matrix_convenience;
 pub mod matrix_functions;
-pub mod matrix_modifiers;
\ No newline at end of file
+pub mod matrix_modifiers;
+pub mod matrix_operators;
+pub mod matrix_reductions;
+pub mod matrix_random;
+pub mod matrix_decomposition;
+pub mod matrix_spectral;
\ No newline at end of file
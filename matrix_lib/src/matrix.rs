@@ -2,17 +2,20 @@ use std::vec;
 
 use super::dimensions::Dimensions;
 use super::errors::*;
+use super::scalar::Scalar;
+use super::simd;
 
+/// Dense row-major matrix, `f64` unless the element type is given explicitly
 #[derive(Clone)]
-pub struct Matrix {
+pub struct Matrix<T: Scalar = f64> {
     dimensions: Dimensions,
-    content: Vec<f64>,
+    content: Vec<T>,
 }
 
-impl Matrix {
+impl<T: Scalar> Matrix<T> {
     /// primary initializer
-    pub fn new<P>(rows: usize, cols: usize, mut producer: P) -> Self where P: FnMut(usize, usize) -> f64 {
-        let mut content = vec![0.0f64; rows * cols];
+    pub fn new<P>(rows: usize, cols: usize, mut producer: P) -> Self where P: FnMut(usize, usize) -> T {
+        let mut content = vec![T::ZERO; rows * cols];
         for i in 0..rows {
             for j in 0..cols {
                 content[i * cols + j] = producer(i, j);
@@ -24,7 +27,16 @@ impl Matrix {
         }
     }
 
-    pub fn vector(vector: &Vec<f64>) -> MathResult<Self> {
+    /// wraps row-major content, its length must be `rows * cols`
+    pub(crate) fn from_content(rows: usize, cols: usize, content: Vec<T>) -> Self {
+        debug_assert_eq!(content.len(), rows * cols);
+        Matrix {
+            dimensions: Dimensions::new(rows, cols),
+            content,
+        }
+    }
+
+    pub fn vector(vector: &[T]) -> MathResult<Self> {
         let dims = Dimensions::new(vector.len(), 1);
         Ok(Matrix::new(dims.rows(), dims.cols(), |i, _| vector[i]))
     }
@@ -45,7 +57,7 @@ impl Matrix {
         self.dimensions.cols
     }
 
-    pub fn get(&self, row: usize, col: usize) -> MathResult<f64> {
+    pub fn get(&self, row: usize, col: usize) -> MathResult<T> {
         if self.dimensions.is_valid_position(row, col) {
             Ok(self.get_unchecked(row, col))
         } else {
@@ -54,12 +66,12 @@ impl Matrix {
     }
 
     #[inline(always)]
-    pub fn get_unchecked(&self, row: usize, col: usize) -> f64 {        
+    pub fn get_unchecked(&self, row: usize, col: usize) -> T {        
         let pos = self.position(row, col);
         self.content[pos]
     }
 
-    pub fn set(&mut self, row: usize, col: usize, value: f64) -> MathResult<()> {
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> MathResult<()> {
         if self.dimensions.is_valid_position(row, col) {
             self.set_unchecked(row, col, value);
             Ok(())
@@ -69,22 +81,33 @@ impl Matrix {
     }
 
     #[inline(always)]
-    pub fn set_unchecked(&mut self, row: usize, col: usize, value: f64) {
+    pub fn set_unchecked(&mut self, row: usize, col: usize, value: T) {
         let pos = self.position(row, col);
         self.content[pos] = value;
     }
     
     #[inline(always)]
-    pub fn is_same_size(&self, other: &Matrix) -> bool {
+    pub fn is_same_size(&self, other: &Matrix<T>) -> bool {
         self.dimensions == other.dimensions
     }
 
+    /// row-major content
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.content
+    }
+
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.content
+    }
+
     #[inline(always)]
     fn position(&self, row: usize, col: usize) -> usize {
         row * self.dimensions.cols + col
     }
 
-    pub fn map<Op: Fn(f64) -> f64>(&self, operation: Op) -> Self {
+    pub fn map<Op: Fn(T) -> T>(&self, operation: Op) -> Self {
         let content = self.content
                     .iter()
                     .map(|x| operation(*x))
@@ -95,7 +118,7 @@ impl Matrix {
         }
     }
 
-    pub fn modify_other<Op: Fn(f64, f64) -> f64>(&mut self, other: &Matrix, operation: Op) -> MathResult<()> {
+    pub fn modify_other<Op: Fn(T, T) -> T>(&mut self, other: &Matrix<T>, operation: Op) -> MathResult<()> {
         if self.is_same_size(other) {
             for i in 0..self.content.len() {
                 self.content[i] = operation(self.content[i], other.content[i]);
@@ -106,19 +129,46 @@ impl Matrix {
         }        
     }
 
-    pub fn modify<Op: Fn(f64) -> f64>(&mut self, operation: Op) {
+    pub fn modify<Op: Fn(T) -> T>(&mut self, operation: Op) {
         for i in 0..self.content.len() {
             self.content[i] = operation(self.content[i]);
         }
     }
 
-    pub fn mean(&self) -> f64 {
-        self.content.iter().fold(0.0, |acc, v| acc + v) / self.content.len() as f64
+    pub fn sum(&self) -> T {
+        simd::sum(&self.content)
+    }
+
+    pub fn mean(&self) -> T {
+        self.sum() / T::from_f64(self.content.len() as f64)
+    }
+
+    /// the same row-major content with other dimensions of the same size
+    pub fn reshape(self, rows: usize, cols: usize) -> MathResult<Self> {
+        let dimensions = Dimensions::new(rows, cols);
+        if dimensions.size() == self.dimensions.size() {
+            Ok(Matrix { dimensions, content: self.content })
+        } else {
+            Err(MathError::IncorrectMatricesDimensions("reshape".to_string(), self.dimensions, dimensions))
+        }
+    }
+
+    /// false if any element is NaN or infinite
+    pub fn is_finite(&self) -> bool {
+        self.content.iter().all(|x| x.is_finite())
+    }
+
+    /// converts every element to another scalar type
+    pub fn cast<U: Scalar>(&self) -> Matrix<U> {
+        Matrix {
+            dimensions: self.dimensions,
+            content: self.content.iter().map(|x| U::from_f64(x.to_f64())).collect(),
+        }
     }
 }
 
-impl std::ops::Index<usize> for Matrix {
-    type Output = [f64];
+impl<T: Scalar> std::ops::Index<usize> for Matrix<T> {
+    type Output = [T];
 
     fn index(&self, index: usize) -> &Self::Output {
         let cols = self.cols();
@@ -127,7 +177,7 @@ impl std::ops::Index<usize> for Matrix {
     }
 }
 
-impl std::ops::IndexMut<usize> for Matrix {
+impl<T: Scalar> std::ops::IndexMut<usize> for Matrix<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         let cols = self.cols();
         let pos = index * cols;
@@ -135,13 +185,13 @@ impl std::ops::IndexMut<usize> for Matrix {
     }
 }
 
-pub fn map<Op: Fn(f64, f64) -> f64>(a: &Matrix, b: &Matrix, operation: Op) -> MathResult<Matrix> {
-    if a.is_same_size(&b) {
-        let size = a.content.len();
-        let mut vector = vec![0.0; size];
-        for i in 0..size {
-            vector[i] = operation(a.content[i], b.content[i]);
-        }
+pub fn map<T: Scalar, Op: Fn(T, T) -> T>(a: &Matrix<T>, b: &Matrix<T>, operation: Op) -> MathResult<Matrix<T>> {
+    if a.is_same_size(b) {
+        let vector = a.content
+                    .iter()
+                    .zip(b.content.iter())
+                    .map(|(x, y)| operation(*x, *y))
+                    .collect();
         Ok(
             Matrix { 
                 dimensions: a.dimensions, 
@@ -181,9 +231,9 @@ mod tests {
         let dims = Dimensions::from_vector(&vector)?;
         let mut m = Matrix::zero(dims.rows(), dims.cols());
         // 
-        for i in 0..dims.rows() {
-            for j in 0..dims.cols() {
-                m.set(i, j, vector[i][j])?;
+        for (i, row) in vector.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                m.set(i, j, *value)?;
             }
         }
         // 
@@ -236,15 +286,15 @@ mod tests {
         let dims = Dimensions::from_vector(&vector)?;
         let mut m = Matrix::zero(dims.rows(), dims.cols());
 
-        for i in 0..m.rows() {
-            for j in 0..m.cols() {
-                m[i][j] = vector[i][j];
+        for (i, row) in vector.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                m[i][j] = *value;
             }
         }
 
-        for i in 0..m.rows() {
-            for j in 0..m.cols() {
-                assert_eq!(m.get(i, j)?, vector[i][j], "Value at {}:{} was written incorrectly", i, j);
+        for (i, row) in vector.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                assert_eq!(m.get(i, j)?, *value, "Value at {}:{} was written incorrectly", i, j);
             }
         }
 
@@ -265,7 +315,7 @@ mod tests {
 
     #[test]
     fn matrix_mean() -> MathResult<()> {
-        let m = Matrix::from_vector(&vec![
+        let m = Matrix::from_vector(&[
             vec![1.0, 2.0],
             vec![3.0, 4.0],
         ])?;
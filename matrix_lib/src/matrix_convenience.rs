@@ -1,50 +1,64 @@
 use super::{
     dimensions::Dimensions,
     matrix::Matrix,
+    scalar::Scalar,
     errors::*,
 };
-use rand::Rng;
+use rand::{
+    Rng,
+    SeedableRng,
+    rngs::StdRng,
+};
 
-impl Matrix {  
+impl<T: Scalar> Matrix<T> {
     /// convenience initializers
-    pub fn new_square<P>(dimension: usize, producer: P) -> Self where P: FnMut(usize, usize) -> f64 {
+    pub fn new_square<P>(dimension: usize, producer: P) -> Self where P: FnMut(usize, usize) -> T {
         Self::new(dimension, dimension, producer)
     }
 
     pub fn identity(dimension: usize) -> Self {
         Self::new_square(
             dimension,
-            |row, col| if row == col { 1.0 } else { 0.0 }
+            |row, col| if row == col { T::ONE } else { T::ZERO }
         )
     }
 
-    pub fn diagonal(vector: &Vec<f64>) -> Self {
+    pub fn diagonal(vector: &[T]) -> Self {
         let dimension = vector.len();
         Self::new_square(
             dimension,
-            |row, col| if row == col { vector[row] } else { 0.0 }
+            |row, col| if row == col { vector[row] } else { T::ZERO }
         )
     }
 
-    pub fn random(rows: usize, cols: usize) -> Self {
-        let mut rng = rand::thread_rng();
-        Matrix::new(rows, cols, |_, _| rng.gen::<f64>())
-    }
-
     pub fn zero(rows: usize, cols: usize) -> Self {
-        Self::new(rows, cols, |_, _| 0.0)
+        Self::from_content(rows, cols, vec![T::ZERO; rows * cols])
     }
 
     pub fn empty() -> Self {
         Matrix::zero(0, 0)
     }
 
-    pub fn from_vector(vector: &Vec<Vec<f64>>) -> MathResult<Self> {
+    pub fn from_vector(vector: &[Vec<T>]) -> MathResult<Self> {
         let dims = Dimensions::from_vector(vector)?;
         Ok(Matrix::new(dims.rows(), dims.cols(), |i, j| vector[i][j]))
     }
 
-    pub fn from_scalar(scalar: f64) -> MathResult<Self> {
-        Self::from_vector(&vec![vec![scalar]])
+    pub fn from_scalar(scalar: T) -> MathResult<Self> {
+        Self::from_vector(&[vec![scalar]])
+    }
+}
+
+/// Random matrices are sampled in `f64`, use `Matrix::cast` to get other element types
+impl Matrix {
+    pub fn random(rows: usize, cols: usize) -> Self {
+        let mut rng = rand::thread_rng();
+        Matrix::new(rows, cols, |_, _| rng.gen::<f64>())
+    }
+
+    /// uniform [0, 1) values, the same seed always produces the same matrix
+    pub fn random_seeded(rows: usize, cols: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Matrix::new(rows, cols, |_, _| rng.gen::<f64>())
     }
-}
\ No newline at end of file
+}
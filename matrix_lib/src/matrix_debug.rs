@@ -1,15 +1,33 @@
 use super::matrix::*;
+use super::matrix_view::*;
+use super::scalar::Scalar;
 use std::fmt;
 
-impl fmt::Debug for Matrix {
+impl<T: Scalar> fmt::Debug for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Matrix [{}x{}]", self.rows(), self.cols())?;
-        for i in 0..self.rows() {
-            for j in 0..self.cols() {
-                write!(f, "{:8.3}", self[i][j])?;
-            }
-            writeln!(f)?;
+        write_content(f, "Matrix", &self.view())
+    }
+}
+
+impl<T: Scalar> fmt::Debug for MatrixView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_content(f, "MatrixView", self)
+    }
+}
+
+impl<T: Scalar> fmt::Debug for MatrixViewMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_content(f, "MatrixViewMut", &self.view())
+    }
+}
+
+fn write_content<T: Scalar>(f: &mut fmt::Formatter<'_>, name: &str, view: &MatrixView<'_, T>) -> fmt::Result {
+    writeln!(f, "{} [{}x{}]", name, view.rows(), view.cols())?;
+    for i in 0..view.rows() {
+        for j in 0..view.cols() {
+            write!(f, "{:8.3}", view.get_unchecked(i, j))?;
         }
-        Ok(())
+        writeln!(f)?;
     }
-}
\ No newline at end of file
+    Ok(())
+}
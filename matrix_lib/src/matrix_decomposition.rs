@@ -0,0 +1,329 @@
+use super::{
+    matrix::Matrix,
+    errors::*,
+};
+
+/// relative tolerance for symmetry checks, scaled by the largest element
+const SYMMETRY_TOLERANCE: f64 = 1e-10;
+
+/// relative tolerance to treat pivots as zero
+fn singularity_tolerance(matrix: &Matrix) -> f64 {
+    f64::EPSILON * matrix.rows().max(matrix.cols()) as f64 * matrix.max_abs()
+}
+
+pub(crate) fn check_symmetric(matrix: &Matrix, op_name: &str) -> MathResult<()> {
+    check_square(matrix, op_name)?;
+    if matrix.is_symmetric(SYMMETRY_TOLERANCE * matrix.max_abs().max(1.0)) {
+        Ok(())
+    } else {
+        Err(MathError::NotSymmetricMatrix(op_name.to_string()))
+    }
+}
+
+pub(crate) fn check_square(matrix: &Matrix, op_name: &str) -> MathResult<()> {
+    if matrix.dimensions().is_square() {
+        Ok(())
+    } else {
+        Err(MathError::NotSquareMatrix(op_name.to_string(), matrix.dimensions()))
+    }
+}
+
+/// LU decomposition with partial pivoting `P * A = L * U`
+pub struct LuDecomposition {
+    /// unit lower triangular L below the diagonal and U on and above it
+    lu: Matrix,
+    /// row i of P * A is row permutation[i] of A
+    permutation: Vec<usize>,
+    /// +1 or -1 depending on parity of the row swaps
+    sign: f64,
+    tolerance: f64,
+}
+
+impl LuDecomposition {
+    pub fn new(matrix: &Matrix) -> MathResult<Self> {
+        check_square(matrix, "lu")?;
+        let n = matrix.rows();
+        let mut lu = matrix.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|a, b| lu[*a][k].abs().total_cmp(&lu[*b][k].abs()))
+                .unwrap_or(k);
+            if pivot_row != k {
+                for j in 0..n {
+                    let tmp = lu[k][j];
+                    lu[k][j] = lu[pivot_row][j];
+                    lu[pivot_row][j] = tmp;
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+            let pivot = lu[k][k];
+            if pivot == 0.0 {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = lu[i][k] / pivot;
+                lu[i][k] = factor;
+                for j in k + 1..n {
+                    lu[i][j] -= factor * lu[k][j];
+                }
+            }
+        }
+        Ok(Self {
+            tolerance: singularity_tolerance(matrix),
+            lu,
+            permutation,
+            sign,
+        })
+    }
+
+    pub fn l(&self) -> Matrix {
+        let n = self.lu.rows();
+        Matrix::new_square(n, |i, j| {
+            if i == j {
+                1.0
+            } else if i > j {
+                self.lu[i][j]
+            } else {
+                0.0
+            }
+        })
+    }
+
+    pub fn u(&self) -> Matrix {
+        let n = self.lu.rows();
+        Matrix::new_square(n, |i, j| if i <= j { self.lu[i][j] } else { 0.0 })
+    }
+
+    /// permutation matrix P
+    pub fn p(&self) -> Matrix {
+        let n = self.lu.rows();
+        Matrix::new_square(n, |i, j| if self.permutation[i] == j { 1.0 } else { 0.0 })
+    }
+
+    pub fn is_singular(&self) -> bool {
+        (0..self.lu.rows()).any(|i| self.lu[i][i].abs() <= self.tolerance)
+    }
+
+    pub fn determinant(&self) -> f64 {
+        (0..self.lu.rows()).fold(self.sign, |acc, i| acc * self.lu[i][i])
+    }
+
+    /// solves A * X = B for each column of B
+    pub fn solve(&self, b: &Matrix) -> MathResult<Matrix> {
+        let n = self.lu.rows();
+        if b.rows() != n {
+            return Err(MathError::IncorrectMatricesDimensions("solve".to_string(), self.lu.dimensions(), b.dimensions()));
+        }
+        if self.is_singular() {
+            return Err(MathError::SingularMatrix("solve".to_string()));
+        }
+        let mut x = Matrix::new(n, b.cols(), |i, j| b[self.permutation[i]][j]);
+        for col in 0..b.cols() {
+            // forward substitution with unit diagonal L
+            for i in 0..n {
+                let sum: f64 = (0..i).map(|k| self.lu[i][k] * x[k][col]).sum();
+                x[i][col] -= sum;
+            }
+            // back substitution with U
+            for i in (0..n).rev() {
+                let sum: f64 = (i + 1..n).map(|k| self.lu[i][k] * x[k][col]).sum();
+                x[i][col] = (x[i][col] - sum) / self.lu[i][i];
+            }
+        }
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> MathResult<Matrix> {
+        self.solve(&Matrix::identity(self.lu.rows()))
+    }
+}
+
+/// Householder QR decomposition `A = Q * R` of (m x n) matrix.
+/// Q and R are returned in reduced form: (m x k) and (k x n) where k = min(m, n)
+pub struct QrDecomposition {
+    /// R on and above the diagonal
+    qr: Matrix,
+    /// normalized Householder vectors, reflector k acts on rows k..m
+    reflectors: Vec<Vec<f64>>,
+}
+
+impl QrDecomposition {
+    pub fn new(matrix: &Matrix) -> Self {
+        let (m, n) = (matrix.rows(), matrix.cols());
+        let mut qr = matrix.clone();
+        let mut reflectors = Vec::with_capacity(m.min(n));
+        for k in 0..m.min(n) {
+            let norm = (k..m).map(|i| qr[i][k] * qr[i][k]).sum::<f64>().sqrt();
+            let mut v: Vec<f64> = (k..m).map(|i| qr[i][k]).collect();
+            if norm > 0.0 {
+                let alpha = if v[0] > 0.0 { -norm } else { norm };
+                v[0] -= alpha;
+                let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+                if v_norm > 0.0 {
+                    v.iter_mut().for_each(|x| *x /= v_norm);
+                }
+            } else {
+                v.iter_mut().for_each(|x| *x = 0.0);
+            }
+            reflect(&mut qr, &v, k, k);
+            reflectors.push(v);
+        }
+        Self { qr, reflectors }
+    }
+
+    pub fn q(&self) -> Matrix {
+        let (m, n) = (self.qr.rows(), self.qr.cols());
+        let k = m.min(n);
+        let mut q = Matrix::new(m, k, |i, j| if i == j { 1.0 } else { 0.0 });
+        for (index, v) in self.reflectors.iter().enumerate().rev() {
+            reflect(&mut q, v, index, 0);
+        }
+        q
+    }
+
+    pub fn r(&self) -> Matrix {
+        let (m, n) = (self.qr.rows(), self.qr.cols());
+        Matrix::new(m.min(n), n, |i, j| if i <= j { self.qr[i][j] } else { 0.0 })
+    }
+
+    /// numerical rank estimated from the diagonal of R
+    pub fn rank(&self) -> usize {
+        let k = self.qr.rows().min(self.qr.cols());
+        let max = (0..k).fold(0.0f64, |acc, i| acc.max(self.qr[i][i].abs()));
+        let tolerance = f64::EPSILON * self.qr.rows().max(self.qr.cols()) as f64 * max;
+        (0..k).filter(|i| self.qr[*i][*i].abs() > tolerance).count()
+    }
+
+    /// minimizes ||A * X - B|| for each column of B, requires m >= n and full column rank
+    pub fn solve_least_squares(&self, b: &Matrix) -> MathResult<Matrix> {
+        let (m, n) = (self.qr.rows(), self.qr.cols());
+        if m < n || b.rows() != m {
+            return Err(MathError::IncorrectMatricesDimensions("least squares".to_string(), self.qr.dimensions(), b.dimensions()));
+        }
+        let rank = self.rank();
+        if rank < n {
+            return Err(MathError::RankDeficientMatrix("least squares".to_string(), rank));
+        }
+        // Q^T * B
+        let mut y = b.clone();
+        for (index, v) in self.reflectors.iter().enumerate() {
+            reflect(&mut y, v, index, 0);
+        }
+        let mut x = Matrix::zero(n, b.cols());
+        for col in 0..b.cols() {
+            for i in (0..n).rev() {
+                let sum: f64 = (i + 1..n).map(|k| self.qr[i][k] * x[k][col]).sum();
+                x[i][col] = (y[i][col] - sum) / self.qr[i][i];
+            }
+        }
+        Ok(x)
+    }
+}
+
+/// applies `H = I - 2 * v * v^T` to rows `row..` and columns `col..` of the matrix
+fn reflect(matrix: &mut Matrix, v: &[f64], row: usize, col: usize) {
+    for j in col..matrix.cols() {
+        let dot: f64 = v.iter().enumerate().map(|(i, x)| x * matrix[row + i][j]).sum();
+        for (i, x) in v.iter().enumerate() {
+            matrix[row + i][j] -= 2.0 * x * dot;
+        }
+    }
+}
+
+/// Cholesky decomposition `A = L * L^T` of symmetric positive-definite matrix
+pub struct CholeskyDecomposition {
+    l: Matrix,
+}
+
+impl CholeskyDecomposition {
+    pub fn new(matrix: &Matrix) -> MathResult<Self> {
+        check_symmetric(matrix, "cholesky")?;
+        let n = matrix.rows();
+        let mut l = Matrix::zero(n, n);
+        for j in 0..n {
+            let diagonal = matrix[j][j] - (0..j).map(|k| l[j][k] * l[j][k]).sum::<f64>();
+            if diagonal <= 0.0 || !diagonal.is_finite() {
+                return Err(MathError::NotPositiveDefiniteMatrix("cholesky".to_string()));
+            }
+            let value = diagonal.sqrt();
+            l[j][j] = value;
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+                l[i][j] = (matrix[i][j] - sum) / value;
+            }
+        }
+        Ok(Self { l })
+    }
+
+    /// lower triangular factor
+    pub fn l(&self) -> Matrix {
+        self.l.clone()
+    }
+
+    /// solves A * X = B with forward and back substitution
+    pub fn solve(&self, b: &Matrix) -> MathResult<Matrix> {
+        let n = self.l.rows();
+        if b.rows() != n {
+            return Err(MathError::IncorrectMatricesDimensions("cholesky solve".to_string(), self.l.dimensions(), b.dimensions()));
+        }
+        let mut x = b.clone();
+        for col in 0..b.cols() {
+            // L * y = b
+            for i in 0..n {
+                let sum: f64 = (0..i).map(|k| self.l[i][k] * x[k][col]).sum();
+                x[i][col] = (x[i][col] - sum) / self.l[i][i];
+            }
+            // L^T * x = y
+            for i in (0..n).rev() {
+                let sum: f64 = (i + 1..n).map(|k| self.l[k][i] * x[k][col]).sum();
+                x[i][col] = (x[i][col] - sum) / self.l[i][i];
+            }
+        }
+        Ok(x)
+    }
+
+    pub fn determinant(&self) -> f64 {
+        (0..self.l.rows()).fold(1.0, |acc, i| acc * self.l[i][i]).powi(2)
+    }
+}
+
+/// least squares solution of overdetermined system `a * x = b`
+pub fn least_squares(a: &Matrix, b: &Matrix) -> MathResult<Matrix> {
+    a.qr().solve_least_squares(b)
+}
+
+/// Decompositions are implemented for `f64` only, use `Matrix::cast` for other element types
+impl Matrix {
+    pub fn cholesky(&self) -> MathResult<CholeskyDecomposition> {
+        CholeskyDecomposition::new(self)
+    }
+
+    /// solves self * X = b for symmetric positive-definite matrix
+    pub fn cholesky_solve(&self, b: &Matrix) -> MathResult<Matrix> {
+        self.cholesky()?.solve(b)
+    }
+
+    pub fn qr(&self) -> QrDecomposition {
+        QrDecomposition::new(self)
+    }
+
+    pub fn lu(&self) -> MathResult<LuDecomposition> {
+        LuDecomposition::new(self)
+    }
+
+    pub fn determinant(&self) -> MathResult<f64> {
+        Ok(self.lu()?.determinant())
+    }
+
+    pub fn inverse(&self) -> MathResult<Matrix> {
+        self.lu()?.inverse()
+    }
+
+    /// solves self * X = b
+    pub fn solve(&self, b: &Matrix) -> MathResult<Matrix> {
+        self.lu()?.solve(b)
+    }
+}
@@ -1,17 +1,19 @@
 use super::{
     matrix::Matrix,
-    matrix::map,
+    matrix_view::*,
+    scalar::Scalar,
+    simd,
     errors::*,
 };
 
-impl PartialEq for Matrix {
+impl<T: Scalar> PartialEq for Matrix<T> {
     fn eq(&self, other: &Self) -> bool {
         if self.is_same_size(other) {
             let rows = self.rows();
             let cols = self.cols();
             for i in 0..rows {
                 for j in 0..cols {
-                    if (self[i][j] - other[i][j]).abs() > f64::EPSILON {
+                    if (self[i][j] - other[i][j]).abs() > T::EPSILON {
                         return false;
                     }
                 }
@@ -23,24 +25,24 @@ impl PartialEq for Matrix {
     }
 }
 
-impl Eq for Matrix {
+impl<T: Scalar> Eq for Matrix<T> {
     //
 }
 
-impl Matrix {
-    pub fn add(&self, other: &Matrix) -> MathResult<Self> {
+impl<T: Scalar> Matrix<T> {
+    pub fn add(&self, other: &impl AsMatrixView<T>) -> MathResult<Self> {
         add(self, other)
     }
 
-    pub fn sub(&self, other: &Matrix) -> MathResult<Self> {
+    pub fn sub(&self, other: &impl AsMatrixView<T>) -> MathResult<Self> {
         sub(self, other)
     }
 
-    pub fn div(&self, other: &Matrix) -> MathResult<Self> {
+    pub fn div(&self, other: &impl AsMatrixView<T>) -> MathResult<Self> {
         div(self, other)
     }
 
-    pub fn product(&self, other: &Matrix) -> MathResult<Self> {
+    pub fn product(&self, other: &impl AsMatrixView<T>) -> MathResult<Self> {
         product(self, other)
     }
 
@@ -54,68 +56,256 @@ impl Matrix {
     pub fn powi(&self, power: i32) -> Self {
         Self::map(self, |x| x.powi(power))
     }
+
+    /// element-wise comparison with absolute tolerance
+    pub fn approx_eq(&self, other: &Matrix<T>, tolerance: T) -> bool {
+        self.is_same_size(other) && (0..self.rows()).all(|i| {
+            self[i].iter()
+                .zip(other[i].iter())
+                .all(|(a, b)| (*a - *b).abs() <= tolerance)
+        })
+    }
+
+    pub fn is_symmetric(&self, tolerance: T) -> bool {
+        self.dimensions().is_square() && (0..self.rows()).all(|i| {
+            (0..i).all(|j| (self[i][j] - self[j][i]).abs() <= tolerance)
+        })
+    }
+
+    /// sum of element-wise products
+    pub fn dot(&self, other: &Matrix<T>) -> MathResult<T> {
+        if self.is_same_size(other) {
+            Ok(simd::dot(self.as_slice(), other.as_slice()))
+        } else {
+            Err(MathError::IncorrectMatricesDimensions("dot".to_string(), self.dimensions(), other.dimensions()))
+        }
+    }
+
+    /// the largest absolute value of the elements
+    pub fn max_abs(&self) -> T {
+        self.as_slice()
+            .iter()
+            .fold(T::ZERO, |acc, x| acc.max(x.abs()))
+    }
 }
 
-pub trait MatrixMultiplication<T> {
+pub trait MatrixMultiplication<V> {
     type ResultType;
-    fn mul(&self, value: T) -> Self::ResultType;
+    fn mul(&self, value: V) -> Self::ResultType;
 }
 
-impl MatrixMultiplication<f64> for Matrix {
-    type ResultType = Matrix;
-    fn mul(&self, value: f64) -> Self::ResultType {
-        self.map(|x| x * value)
+impl<T: Scalar> MatrixMultiplication<T> for Matrix<T> {
+    type ResultType = Matrix<T>;
+    fn mul(&self, value: T) -> Self::ResultType {
+        let mut matrix = self.clone();
+        simd::mul_scalar(matrix.as_mut_slice(), value);
+        matrix
     }
 }
 
-impl MatrixMultiplication<&Matrix> for Matrix {
-    type ResultType = MathResult<Matrix>;
-    fn mul(&self, value: &Matrix) -> Self::ResultType {
+impl<T: Scalar> MatrixMultiplication<&Matrix<T>> for Matrix<T> {
+    type ResultType = MathResult<Matrix<T>>;
+    fn mul(&self, value: &Matrix<T>) -> Self::ResultType {
         mul(self, value)
     }
 }
 
+/// applies vectorized kernel to matrices or views of the same size, strided views are processed row by row
+fn elementwise<T: Scalar>(first: MatrixView<'_, T>, second: MatrixView<'_, T>, kernel: fn(&[T], &[T]) -> Vec<T>) -> MathResult<Matrix<T>> {
+    if first.dimensions() != second.dimensions() {
+        return Err(MathError::IncorrectMatricesDimensions("element wise".to_string(), first.dimensions(), second.dimensions()));
+    }
+    let content = match (first.as_slice(), second.as_slice()) {
+        (Some(a), Some(b)) => kernel(a, b),
+        _ => {
+            let mut content = Vec::with_capacity(first.dimensions().size());
+            for i in 0..first.rows() {
+                content.extend(kernel(&first.row_content(i), &second.row_content(i)));
+            }
+            content
+        }
+    };
+    Ok(Matrix::from_content(first.rows(), first.cols(), content))
+}
+
 /// Elementwise sum
 #[inline]
-pub fn add(first: &Matrix, second: &Matrix) -> MathResult<Matrix> {
-    map(first, second, |x, y| x + y)
+pub fn add<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    elementwise(first.as_view(), second.as_view(), simd::add)
 }
 
 /// Elementwise subtraction
 #[inline]
-pub fn sub(first: &Matrix, second: &Matrix) -> MathResult<Matrix> {
-    map(first, second, |x, y| x - y)
+pub fn sub<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    elementwise(first.as_view(), second.as_view(), simd::sub)
 }
 
 /// Elementwise multiplication
 #[inline]
-pub fn mul(first: &Matrix, second: &Matrix) -> MathResult<Matrix> {
-    map(first, second, |x, y| x * y)
+pub fn mul<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    elementwise(first.as_view(), second.as_view(), simd::mul)
 }
 
 /// Elementwise division
 #[inline]
-pub fn div(first: &Matrix, second: &Matrix) -> MathResult<Matrix> {
-    map(first, second, |x, y| x / y)
+pub fn div<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    elementwise(first.as_view(), second.as_view(), simd::div)
+}
+
+/// repeats single rows and columns of the operands to their common dimensions
+fn broadcast<T: Scalar>(name: &str, first: MatrixView<'_, T>, second: MatrixView<'_, T>, kernel: fn(&[T], &[T]) -> Vec<T>) -> MathResult<Matrix<T>> {
+    let dimensions = first.dimensions()
+        .broadcast(&second.dimensions())
+        .ok_or_else(|| MathError::IncompatibleBroadcast(name.to_string(), first.dimensions(), second.dimensions()))?;
+    elementwise(first.broadcast(dimensions)?, second.broadcast(dimensions)?, kernel)
+}
+
+/// Elementwise sum with NumPy-style broadcasting, e.g. `(n x batch) + (n x 1)`
+pub fn broadcast_add<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    broadcast("broadcast add", first.as_view(), second.as_view(), simd::add)
+}
+
+/// Elementwise subtraction with broadcasting
+pub fn broadcast_sub<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    broadcast("broadcast sub", first.as_view(), second.as_view(), simd::sub)
+}
+
+/// Elementwise multiplication with broadcasting
+pub fn broadcast_mul<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    broadcast("broadcast mul", first.as_view(), second.as_view(), simd::mul)
+}
+
+/// Elementwise division with broadcasting
+pub fn broadcast_div<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    broadcast("broadcast div", first.as_view(), second.as_view(), simd::div)
+}
+
+/// column-wise softmax shifted by the column maximum for numerical stability
+pub fn softmax<T: Scalar>(matrix: &Matrix<T>) -> Matrix<T> {
+    let mut result = matrix.clone();
+    for j in 0..matrix.cols() {
+        let max = column_max(matrix, j);
+        let mut sum = T::ZERO;
+        for i in 0..matrix.rows() {
+            let value = (matrix[i][j] - max).exp();
+            result[i][j] = value;
+            sum += value;
+        }
+        for i in 0..matrix.rows() {
+            result[i][j] /= sum;
+        }
+    }
+    result
+}
+
+/// `ln(sum(exp(x)))` of every column as a row vector, shifted by the column maximum to avoid overflow
+pub fn log_sum_exp<T: Scalar>(matrix: &Matrix<T>) -> Matrix<T> {
+    Matrix::new(1, matrix.cols(), |_, j| {
+        let max = column_max(matrix, j);
+        (0..matrix.rows())
+            .map(|i| (matrix[i][j] - max).exp())
+            .sum::<T>()
+            .ln() + max
+    })
+}
+
+fn column_max<T: Scalar>(matrix: &Matrix<T>, col: usize) -> T {
+    (0..matrix.rows())
+        .map(|i| matrix[i][col])
+        .fold(T::from_f64(f64::NEG_INFINITY), T::max)
 }
 
-/// Matrix product
-pub fn product(first: &Matrix, second: &Matrix) -> MathResult<Matrix> {
+/// rows, columns and inner dimension of the tiles processed by product kernel
+const BLOCK_SIZE: usize = 64;
+
+/// products with less multiply-adds aren't worth spawning threads
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 18;
+
+/// Left operand of product kernel, arbitrary strides are allowed since its elements are read one by one
+#[derive(Copy, Clone)]
+struct Strided<'a, T> {
+    content: &'a [T],
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<T: Scalar> Strided<'_, T> {
+    #[inline(always)]
+    fn get(&self, row: usize, col: usize) -> T {
+        self.content[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+/// Matrix product, views are multiplied in place unless the right one has non-adjacent row elements
+pub fn product<T: Scalar>(first: &impl AsMatrixView<T>, second: &impl AsMatrixView<T>) -> MathResult<Matrix<T>> {
+    let (first, second) = (first.as_view(), second.as_view());
     let (rows, fc) = (first.rows(), first.cols());
     let (sr, cols) = (second.rows(), second.cols());
     if fc == sr {
         let mut matrix = Matrix::zero(rows, cols);
-        for i in 0..rows {            
-            for j in 0..cols {
-                let mut sum = 0.0f64;
-                for k in 0..fc {
-                    sum += first.get_unchecked(i, k) * second.get_unchecked(k, j);
+        if rows > 0 && cols > 0 && fc > 0 {
+            let (content, row_stride, col_stride) = first.strided();
+            let first = Strided { content, row_stride, col_stride };
+            let packed;
+            let (second, second_stride) = match second.strided() {
+                (content, row_stride, 1) => (content, row_stride),
+                _ => {
+                    packed = second.to_matrix();
+                    (packed.as_slice(), cols)
                 }
-                matrix.set_unchecked(i, j, sum);
-            }
+            };
+            product_kernel(first, second, second_stride, matrix.as_mut_slice(), fc, cols);
         }
         Ok(matrix)
     } else {
         Err(MathError::IncorrectMatricesDimensions("product".to_string(), first.dimensions(), second.dimensions()))
-    }        
-}
\ No newline at end of file
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn product_kernel<T: Scalar>(first: Strided<'_, T>, second: &[T], second_stride: usize, result: &mut [T], inner: usize, cols: usize) {
+    product_block(first, 0, second, second_stride, result, inner, cols);
+}
+
+/// splits rows of the result between available threads
+#[cfg(feature = "parallel")]
+fn product_kernel<T: Scalar>(first: Strided<'_, T>, second: &[T], second_stride: usize, result: &mut [T], inner: usize, cols: usize) {
+    let rows = result.len() / cols;
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get()).min(rows);
+    if threads < 2 || rows * cols * inner < PARALLEL_THRESHOLD {
+        product_block(first, 0, second, second_stride, result, inner, cols);
+        return;
+    }
+    let rows_per_thread = rows.div_ceil(threads);
+    std::thread::scope(|scope| {
+        for (index, result_rows) in result.chunks_mut(rows_per_thread * cols).enumerate() {
+            scope.spawn(move || product_block(first, index * rows_per_thread, second, second_stride, result_rows, inner, cols));
+        }
+    });
+}
+
+/// Cache-blocked i-k-j product: `result += first[first_row..] * second`, where `second` rows are `second_stride` apart.
+/// The innermost loop walks rows of `second` and `result` contiguously, so it's vectorized by compiler
+fn product_block<T: Scalar>(first: Strided<'_, T>, first_row: usize, second: &[T], second_stride: usize, result: &mut [T], inner: usize, cols: usize) {
+    let rows = result.len() / cols;
+    for ii in (0..rows).step_by(BLOCK_SIZE) {
+        let i_end = (ii + BLOCK_SIZE).min(rows);
+        for kk in (0..inner).step_by(BLOCK_SIZE) {
+            let k_end = (kk + BLOCK_SIZE).min(inner);
+            for jj in (0..cols).step_by(BLOCK_SIZE) {
+                let j_end = (jj + BLOCK_SIZE).min(cols);
+                for i in ii..i_end {
+                    let result_row = &mut result[i * cols + jj..i * cols + j_end];
+                    for k in kk..k_end {
+                        let a = first.get(first_row + i, k);
+                        let second_row = &second[k * second_stride + jj..k * second_stride + j_end];
+                        for (r, b) in result_row.iter_mut().zip(second_row) {
+                            *r += a * *b;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
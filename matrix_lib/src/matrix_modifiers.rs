@@ -1,5 +1,7 @@
 use super::{
     matrix::Matrix,
+    scalar::Scalar,
+    simd,
     errors::*,
 };
 use std::ops::{
@@ -9,56 +11,66 @@ use std::ops::{
     DivAssign,
 };
 
-impl Matrix {
-    pub fn add_assign(&mut self, other: &Matrix) -> MathResult<()> {
-        self.modify_other(other, |a, b| a + b)        
+impl<T: Scalar> Matrix<T> {
+    pub fn add_assign(&mut self, other: &Matrix<T>) -> MathResult<()> {
+        self.assign_other(other, simd::add_assign)
     }
 
-    pub fn sub_assign(&mut self, other: &Matrix) -> MathResult<()> {
-        self.modify_other(other, |a, b| a - b)
+    pub fn sub_assign(&mut self, other: &Matrix<T>) -> MathResult<()> {
+        self.assign_other(other, simd::sub_assign)
     }
 
-    pub fn mul_assign(&mut self, scalar: f64) {
-        self.modify(|x| x * scalar)
+    pub fn mul_assign(&mut self, scalar: T) {
+        simd::mul_scalar(self.as_mut_slice(), scalar)
     }
 
-    pub fn div_assign(&mut self, scalar: f64) {
-        self.modify(|x| x / scalar)
+    pub fn div_assign(&mut self, scalar: T) {
+        simd::div_scalar(self.as_mut_slice(), scalar)
+    }
+
+    /// in-place element-wise operation through a vectorized kernel
+    fn assign_other(&mut self, other: &Matrix<T>, kernel: fn(&mut [T], &[T])) -> MathResult<()> {
+        if self.is_same_size(other) {
+            kernel(self.as_mut_slice(), other.as_slice());
+            Ok(())
+        } else {
+            Err(MathError::IncorrectMatricesDimensions("element wise mut".to_string(), self.dimensions(), other.dimensions()))
+        }
     }
 }
 
-impl AddAssign<&Self> for Matrix {
+impl<T: Scalar> AddAssign<&Self> for Matrix<T> {
     fn add_assign(&mut self, rhs: &Self) {
         self.add_assign(rhs).expect("add_assign operator: inappropriate matrix sizes");
     }
 }
 
-impl AddAssign for Matrix {
+impl<T: Scalar> AddAssign for Matrix<T> {
     fn add_assign(&mut self, rhs: Self) {
         self.add_assign(&rhs).expect("add_assign operator: inappropriate matrix sizes");
     }
 }
 
-impl SubAssign<&Self> for Matrix {
+impl<T: Scalar> SubAssign<&Self> for Matrix<T> {
     fn sub_assign(&mut self, rhs: &Self) {
         self.sub_assign(rhs).expect("sub_assign operator: inappropriate matrix sizes");
     }
 }
 
-impl SubAssign for Matrix {
+impl<T: Scalar> SubAssign for Matrix<T> {
     fn sub_assign(&mut self, rhs: Self) {
         self.sub_assign(&rhs).expect("sub_assign operator: inappropriate matrix sizes");
     }
 }
 
-impl MulAssign<f64> for Matrix {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Scalar> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.mul_assign(rhs);
     }
 }
 
-impl DivAssign<f64> for Matrix {
-    fn div_assign(&mut self, rhs: f64) {
+impl<T: Scalar> DivAssign<T> for Matrix<T> {
+    fn div_assign(&mut self, rhs: T) {
         self.div_assign(rhs);
     }
 }
\ No newline at end of file
@@ -0,0 +1,174 @@
+use super::{
+    matrix::Matrix,
+    matrix_functions::*,
+    matrix_view::*,
+    scalar::Scalar,
+    simd,
+};
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+};
+
+// Operators panic on inappropriate sizes like the assign ones, use functions from `matrix_functions` to get errors instead.
+// `*` between matrices and views is the matrix product, element-wise multiplication is `matrix_functions::mul`
+
+macro_rules! elementwise_operator {
+    ($trait:ident, $method:ident, $assign:ident) => {
+        impl<T: Scalar> $trait<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                $method(self, rhs).expect(concat!(stringify!($method), " operator: inappropriate matrix sizes"))
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                $trait::$method(self, &rhs)
+            }
+        }
+
+        /// reuses the left operand's content
+        impl<T: Scalar> $trait<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(mut self, rhs: &Matrix<T>) -> Matrix<T> {
+                Matrix::$assign(&mut self, rhs).expect(concat!(stringify!($method), " operator: inappropriate matrix sizes"));
+                self
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                $trait::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+elementwise_operator!(Add, add, add_assign);
+elementwise_operator!(Sub, sub, sub_assign);
+
+/// element-wise operator with a view on either side, always allocates the result
+macro_rules! view_elementwise_operator {
+    ($trait:ident, $method:ident, $lhs:ty, $rhs:ty) => {
+        impl<'a, 'b, T: Scalar> $trait<$rhs> for $lhs {
+            type Output = Matrix<T>;
+            fn $method(self, rhs: $rhs) -> Matrix<T> {
+                $method(&self.as_view(), &rhs.as_view()).expect(concat!(stringify!($method), " operator: inappropriate matrix sizes"))
+            }
+        }
+    };
+}
+
+macro_rules! view_elementwise_operators {
+    ($trait:ident, $method:ident) => {
+        view_elementwise_operator!($trait, $method, Matrix<T>, MatrixView<'b, T>);
+        view_elementwise_operator!($trait, $method, &'a Matrix<T>, MatrixView<'b, T>);
+        view_elementwise_operator!($trait, $method, MatrixView<'a, T>, Matrix<T>);
+        view_elementwise_operator!($trait, $method, MatrixView<'a, T>, &'b Matrix<T>);
+        view_elementwise_operator!($trait, $method, MatrixView<'a, T>, MatrixView<'b, T>);
+    };
+}
+
+view_elementwise_operators!(Add, add);
+view_elementwise_operators!(Sub, sub);
+
+macro_rules! product_operator {
+    ($lhs:ty, $rhs:ty) => {
+        impl<'a, 'b, T: Scalar> Mul<$rhs> for $lhs {
+            type Output = Matrix<T>;
+            fn mul(self, rhs: $rhs) -> Matrix<T> {
+                product(&self.as_view(), &rhs.as_view()).expect("mul operator: inappropriate matrix sizes for product")
+            }
+        }
+    };
+}
+
+product_operator!(Matrix<T>, Matrix<T>);
+product_operator!(Matrix<T>, &'b Matrix<T>);
+product_operator!(Matrix<T>, MatrixView<'b, T>);
+product_operator!(&'a Matrix<T>, Matrix<T>);
+product_operator!(&'a Matrix<T>, &'b Matrix<T>);
+product_operator!(&'a Matrix<T>, MatrixView<'b, T>);
+product_operator!(MatrixView<'a, T>, Matrix<T>);
+product_operator!(MatrixView<'a, T>, &'b Matrix<T>);
+product_operator!(MatrixView<'a, T>, MatrixView<'b, T>);
+
+impl<T: Scalar> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(mut self, rhs: T) -> Matrix<T> {
+        simd::mul_scalar(self.as_mut_slice(), rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, rhs: T) -> Matrix<T> {
+        self.clone() * rhs
+    }
+}
+
+impl<T: Scalar> Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+    fn div(mut self, rhs: T) -> Matrix<T> {
+        simd::div_scalar(self.as_mut_slice(), rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Div<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn div(self, rhs: T) -> Matrix<T> {
+        self.clone() / rhs
+    }
+}
+
+impl<T: Scalar> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(mut self) -> Matrix<T> {
+        self.modify(|x| -x);
+        self
+    }
+}
+
+impl<T: Scalar> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
+
+impl<T: Scalar> Neg for MatrixView<'_, T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Matrix<T> {
+        -self.to_matrix()
+    }
+}
+
+/// scalar on the left can't be generic because of orphan rules
+macro_rules! scalar_left_operator {
+    ($t:ty) => {
+        impl Mul<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+            fn mul(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                rhs * self
+            }
+        }
+
+        impl Mul<&Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+            fn mul(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                rhs * self
+            }
+        }
+    };
+}
+
+scalar_left_operator!(f32);
+scalar_left_operator!(f64);
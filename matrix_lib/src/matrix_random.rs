@@ -0,0 +1,57 @@
+use super::matrix::Matrix;
+use rand::{
+    Rng,
+    seq::SliceRandom,
+};
+
+/// values farther than this number of standard deviations are redrawn by `truncated_normal`
+const TRUNCATION_DEVIATIONS: f64 = 2.0;
+
+/// Random matrices are sampled in `f64`, use `Matrix::cast` to get other element types
+impl Matrix {
+    /// uniform values in [low, high)
+    pub fn random_uniform<R: Rng + ?Sized>(rows: usize, cols: usize, low: f64, high: f64, rng: &mut R) -> Self {
+        Self::new(rows, cols, |_, _| low + (high - low) * rng.gen::<f64>())
+    }
+
+    pub fn random_normal<R: Rng + ?Sized>(rows: usize, cols: usize, mean: f64, std: f64, rng: &mut R) -> Self {
+        Self::new(rows, cols, |_, _| mean + std * standard_normal(rng))
+    }
+
+    /// normal distribution where values outside of 2 standard deviations from mean are redrawn
+    pub fn truncated_normal<R: Rng + ?Sized>(rows: usize, cols: usize, mean: f64, std: f64, rng: &mut R) -> Self {
+        Self::new(rows, cols, |_, _| {
+            loop {
+                let value = standard_normal(rng);
+                if value.abs() <= TRUNCATION_DEVIATIONS {
+                    return mean + std * value;
+                }
+            }
+        })
+    }
+
+    /// 1.0 with probability p and 0.0 otherwise
+    pub fn bernoulli<R: Rng + ?Sized>(rows: usize, cols: usize, p: f64, rng: &mut R) -> Self {
+        Self::new(rows, cols, |_, _| if rng.gen::<f64>() < p { 1.0 } else { 0.0 })
+    }
+
+    /// square permutation matrix, `m.product(p)` shuffles columns of m and `p.product(m)` shuffles rows
+    pub fn random_permutation<R: Rng + ?Sized>(dimension: usize, rng: &mut R) -> Self {
+        let indices = permutation(dimension, rng);
+        Self::new_square(dimension, |row, col| if indices[row] == col { 1.0 } else { 0.0 })
+    }
+}
+
+/// random order of indices 0..count
+pub fn permutation<R: Rng + ?Sized>(count: usize, rng: &mut R) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..count).collect();
+    indices.shuffle(rng);
+    indices
+}
+
+/// Box-Muller transform
+pub fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1: f64 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
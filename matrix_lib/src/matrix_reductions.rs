@@ -0,0 +1,130 @@
+use super::{
+    errors::*,
+    matrix::Matrix,
+    scalar::Scalar,
+    simd,
+};
+
+/// Direction of a reduction, named after the index that is collapsed
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Axis {
+    /// reduces every column over its rows, the result is a row vector `1 x cols`
+    Rows,
+    /// reduces every row over its columns, the result is a column vector `rows x 1`
+    Columns,
+}
+
+impl<T: Scalar> Matrix<T> {
+    pub fn sum_axis(&self, axis: Axis) -> Matrix<T> {
+        match axis {
+            Axis::Rows => {
+                let mut sum = vec![T::ZERO; self.cols()];
+                for i in 0..self.rows() {
+                    simd::add_assign(&mut sum, &self[i]);
+                }
+                self.axis_vector(axis, sum)
+            }
+            Axis::Columns => self.axis_vector(axis, (0..self.rows()).map(|i| simd::sum(&self[i])).collect()),
+        }
+    }
+
+    pub fn mean_axis(&self, axis: Axis) -> MathResult<Matrix<T>> {
+        self.check_axis("mean axis", axis)?;
+        let mut mean = self.sum_axis(axis);
+        simd::div_scalar(mean.as_mut_slice(), T::from_f64(self.axis_len(axis) as f64));
+        Ok(mean)
+    }
+
+    /// population variance, i.e. squared deviations are divided by the number of elements
+    pub fn variance_axis(&self, axis: Axis) -> MathResult<Matrix<T>> {
+        self.check_axis("variance axis", axis)?;
+        let mean = self.mean_axis(axis)?;
+        let mean = mean.as_slice();
+        let n = T::from_f64(self.axis_len(axis) as f64);
+        let squares = self.fold_axis(axis, T::ZERO, |acc, i, j, x| {
+            let d = x - mean[if axis == Axis::Rows { j } else { i }];
+            acc + d * d
+        });
+        Ok(self.axis_vector(axis, squares.into_iter().map(|x| x / n).collect()))
+    }
+
+    pub fn max_axis(&self, axis: Axis) -> MathResult<Matrix<T>> {
+        self.extremum_axis("max axis", axis, |x, best| x > best)
+            .map(|values| self.axis_vector(axis, values.into_iter().map(|(_, x)| x).collect()))
+    }
+
+    pub fn min_axis(&self, axis: Axis) -> MathResult<Matrix<T>> {
+        self.extremum_axis("min axis", axis, |x, best| x < best)
+            .map(|values| self.axis_vector(axis, values.into_iter().map(|(_, x)| x).collect()))
+    }
+
+    /// positions of the first maximum along the axis, e.g. predicted classes of a column-stacked batch for `Axis::Rows`
+    pub fn argmax_axis(&self, axis: Axis) -> MathResult<Vec<usize>> {
+        self.extremum_axis("argmax axis", axis, |x, best| x > best)
+            .map(|values| values.into_iter().map(|(k, _)| k).collect())
+    }
+
+    /// Frobenius norm
+    pub fn norm(&self) -> T {
+        simd::dot(self.as_slice(), self.as_slice()).sqrt()
+    }
+
+    /// euclidean norms of columns (`Axis::Rows`) or rows (`Axis::Columns`)
+    pub fn norm_axis(&self, axis: Axis) -> Matrix<T> {
+        let squares = self.fold_axis(axis, T::ZERO, |acc, _, _, x| acc + x * x);
+        self.axis_vector(axis, squares.into_iter().map(T::sqrt).collect())
+    }
+
+    /// number of elements reduced into a single value
+    fn axis_len(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Rows => self.rows(),
+            Axis::Columns => self.cols(),
+        }
+    }
+
+    /// statistics of an empty axis are undefined
+    fn check_axis(&self, name: &str, axis: Axis) -> MathResult<()> {
+        if self.axis_len(axis) == 0 {
+            Err(MathError::EmptyMatrix(name.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn axis_vector(&self, axis: Axis, values: Vec<T>) -> Matrix<T> {
+        match axis {
+            Axis::Rows => Matrix::from_content(1, values.len(), values),
+            Axis::Columns => Matrix::from_content(values.len(), 1, values),
+        }
+    }
+
+    /// folds every reduced line, the operation gets element's row and column
+    fn fold_axis<A: Clone, Op: Fn(A, usize, usize, T) -> A>(&self, axis: Axis, init: A, operation: Op) -> Vec<A> {
+        let len = match axis {
+            Axis::Rows => self.cols(),
+            Axis::Columns => self.rows(),
+        };
+        let mut result = vec![init; len];
+        for i in 0..self.rows() {
+            for j in 0..self.cols() {
+                let k = if axis == Axis::Rows { j } else { i };
+                result[k] = operation(result[k].clone(), i, j, self[i][j]);
+            }
+        }
+        result
+    }
+
+    /// the first element preferred to all others along the axis and its position there
+    fn extremum_axis<P: Fn(T, T) -> bool>(&self, name: &str, axis: Axis, prefer: P) -> MathResult<Vec<(usize, T)>> {
+        self.check_axis(name, axis)?;
+        let values = self.fold_axis(axis, None, |best: Option<(usize, T)>, i, j, x| {
+            let k = if axis == Axis::Rows { i } else { j };
+            match best {
+                Some((_, value)) if !prefer(x, value) => best,
+                _ => Some((k, x)),
+            }
+        });
+        Ok(values.into_iter().flatten().collect())
+    }
+}
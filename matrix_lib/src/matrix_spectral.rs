@@ -0,0 +1,282 @@
+use super::{
+    matrix::Matrix,
+    matrix_decomposition::check_symmetric,
+    errors::*,
+};
+
+/// upper bound of Jacobi sweeps, the methods usually converge in less than 10
+const MAX_SWEEPS: usize = 100;
+
+/// Returns `(c, s)` of the rotation annihilating the off-diagonal element
+/// of 2x2 symmetric matrix [[app, apq], [apq, aqq]]
+fn jacobi_rotation(app: f64, aqq: f64, apq: f64) -> (f64, f64) {
+    let theta = (aqq - app) / (2.0 * apq);
+    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+    let c = 1.0 / (t * t + 1.0).sqrt();
+    (c, t * c)
+}
+
+/// replaces columns p and q with `c * p - s * q` and `s * p + c * q`
+fn rotate_columns(matrix: &mut Matrix, p: usize, q: usize, c: f64, s: f64) {
+    for k in 0..matrix.rows() {
+        let (x, y) = (matrix[k][p], matrix[k][q]);
+        matrix[k][p] = c * x - s * y;
+        matrix[k][q] = s * x + c * y;
+    }
+}
+
+fn rotate_rows(matrix: &mut Matrix, p: usize, q: usize, c: f64, s: f64) {
+    for k in 0..matrix.cols() {
+        let (x, y) = (matrix[p][k], matrix[q][k]);
+        matrix[p][k] = c * x - s * y;
+        matrix[q][k] = s * x + c * y;
+    }
+}
+
+/// indices sorting values in descending order
+fn descending_order(values: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*b].total_cmp(&values[*a]));
+    order
+}
+
+/// Fills the missing columns of a matrix with orthonormal columns, so all of them become orthonormal.
+/// Every new column is the standard basis vector with the largest residual
+/// after Gram-Schmidt against the columns that are already filled
+fn complete_orthonormal(matrix: &mut Matrix, missing: &[usize]) {
+    let (m, n) = (matrix.rows(), matrix.cols());
+    let mut done: Vec<bool> = (0..n).map(|j| !missing.contains(&j)).collect();
+    for &j in missing {
+        let residual = |e: usize, matrix: &Matrix, done: &[bool]| {
+            let mut r = vec![0.0; m];
+            r[e] = 1.0;
+            // twice is enough to stay orthogonal in floating point
+            for _ in 0..2 {
+                for p in (0..n).filter(|p| done[*p]) {
+                    let dot: f64 = (0..m).map(|k| r[k] * matrix[k][p]).sum();
+                    for k in 0..m {
+                        r[k] -= dot * matrix[k][p];
+                    }
+                }
+            }
+            r
+        };
+        let (_, best) = (0..m)
+            .map(|e| residual(e, matrix, &done))
+            .map(|r| (r.iter().map(|x| x * x).sum::<f64>(), r))
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .expect("matrix has at least one row");
+        let norm = best.iter().map(|x| x * x).sum::<f64>().sqrt();
+        for k in 0..m {
+            matrix[k][j] = best[k] / norm;
+        }
+        done[j] = true;
+    }
+}
+
+/// Eigendecomposition `A = V * diag(eigenvalues) * V^T` of symmetric matrix
+pub struct SymmetricEigen {
+    eigenvalues: Vec<f64>,
+    eigenvectors: Matrix,
+}
+
+impl SymmetricEigen {
+    /// cyclic Jacobi eigenvalue algorithm
+    pub fn new(matrix: &Matrix) -> MathResult<Self> {
+        check_symmetric(matrix, "symmetric eigen")?;
+        let n = matrix.rows();
+        let mut a = matrix.clone();
+        let mut v = Matrix::identity(n);
+        let norm = (0..n).map(|i| a[i].iter().map(|x| x * x).sum::<f64>()).sum::<f64>().sqrt();
+        let tolerance = f64::EPSILON * norm;
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal = (0..n)
+                .map(|i| (0..n).filter(|j| *j != i).map(|j| a[i][j] * a[i][j]).sum::<f64>())
+                .sum::<f64>()
+                .sqrt();
+            if off_diagonal <= tolerance {
+                converged = true;
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+                    let (c, s) = jacobi_rotation(a[p][p], a[q][q], a[p][q]);
+                    rotate_columns(&mut a, p, q, c, s);
+                    rotate_rows(&mut a, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+        }
+        if !converged {
+            return Err(MathError::NotConverged("symmetric eigen".to_string(), MAX_SWEEPS));
+        }
+        let values: Vec<f64> = (0..n).map(|i| a[i][i]).collect();
+        let order = descending_order(&values);
+        Ok(Self {
+            eigenvalues: order.iter().map(|i| values[*i]).collect(),
+            eigenvectors: Matrix::new_square(n, |i, j| v[i][order[j]]),
+        })
+    }
+
+    /// eigenvalues in descending order
+    pub fn eigenvalues(&self) -> &[f64] {
+        &self.eigenvalues
+    }
+
+    /// orthonormal eigenvectors as columns, in the order of eigenvalues
+    pub fn eigenvectors(&self) -> &Matrix {
+        &self.eigenvectors
+    }
+}
+
+/// Thin singular value decomposition `A = U * diag(sigma) * V^T` of (m x n) matrix,
+/// U is (m x k), V^T is (k x n) where k = min(m, n), both with orthonormal columns and rows.
+/// Columns of U that correspond to zero singular values complete the basis of the column space
+pub struct SingularValueDecomposition {
+    u: Matrix,
+    singular_values: Vec<f64>,
+    vt: Matrix,
+}
+
+impl SingularValueDecomposition {
+    /// one-sided Jacobi (Hestenes) method
+    pub fn new(matrix: &Matrix) -> MathResult<Self> {
+        if matrix.rows() < matrix.cols() {
+            let svd = Self::new(&matrix.transpose())?;
+            return Ok(Self {
+                u: svd.vt.transpose(),
+                singular_values: svd.singular_values,
+                vt: svd.u.transpose(),
+            });
+        }
+        let (m, n) = (matrix.rows(), matrix.cols());
+        let mut u = matrix.clone();
+        let mut v = Matrix::identity(n);
+        // columns with smaller squared norm are treated as zero, otherwise rotations never stop for rank deficient input
+        let negligible = (0..m)
+            .map(|i| u[i].iter().map(|x| x * x).sum::<f64>())
+            .sum::<f64>() * f64::EPSILON * f64::EPSILON;
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let alpha: f64 = (0..m).map(|k| u[k][p] * u[k][p]).sum();
+                    let beta: f64 = (0..m).map(|k| u[k][q] * u[k][q]).sum();
+                    let gamma: f64 = (0..m).map(|k| u[k][p] * u[k][q]).sum();
+                    if alpha.min(beta) <= negligible || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let (c, s) = jacobi_rotation(alpha, beta, gamma);
+                    rotate_columns(&mut u, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(MathError::NotConverged("svd".to_string(), MAX_SWEEPS));
+        }
+        let values: Vec<f64> = (0..n)
+            .map(|j| (0..m).map(|k| u[k][j] * u[k][j]).sum::<f64>().sqrt())
+            .collect();
+        let order = descending_order(&values);
+        let zero = |sigma: f64| sigma * sigma <= negligible;
+        let mut u = Matrix::new(m, n, |i, j| {
+            let sigma = values[order[j]];
+            if zero(sigma) { 0.0 } else { u[i][order[j]] / sigma }
+        });
+        let missing: Vec<usize> = (0..n).filter(|j| zero(values[order[*j]])).collect();
+        complete_orthonormal(&mut u, &missing);
+        Ok(Self {
+            u,
+            singular_values: order.iter().map(|i| values[*i]).collect(),
+            vt: Matrix::new_square(n, |i, j| v[j][order[i]]),
+        })
+    }
+
+    pub fn u(&self) -> &Matrix {
+        &self.u
+    }
+
+    /// singular values in descending order
+    pub fn singular_values(&self) -> &[f64] {
+        &self.singular_values
+    }
+
+    pub fn sigma(&self) -> Matrix {
+        Matrix::diagonal(&self.singular_values)
+    }
+
+    pub fn vt(&self) -> &Matrix {
+        &self.vt
+    }
+
+    /// singular values below this value are treated as zero
+    pub fn tolerance(&self) -> f64 {
+        let largest = self.singular_values.first().copied().unwrap_or(0.0);
+        f64::EPSILON * self.u.rows().max(self.vt.cols()) as f64 * largest
+    }
+
+    pub fn rank(&self) -> usize {
+        let tolerance = self.tolerance();
+        self.singular_values.iter().filter(|s| **s > tolerance).count()
+    }
+
+    /// Moore-Penrose pseudo-inverse `V * diag(1 / sigma) * U^T`
+    pub fn pseudo_inverse(&self) -> Matrix {
+        let tolerance = self.tolerance();
+        let (m, n) = (self.u.rows(), self.vt.cols());
+        Matrix::new(n, m, |i, j| {
+            self.singular_values
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| **s > tolerance)
+                .map(|(k, s)| self.vt[k][i] * self.u[j][k] / s)
+                .sum()
+        })
+    }
+}
+
+/// Spectral functions are implemented for `f64` only, use `Matrix::cast` for other element types
+impl Matrix {
+    pub fn symmetric_eigen(&self) -> MathResult<SymmetricEigen> {
+        SymmetricEigen::new(self)
+    }
+
+    pub fn svd(&self) -> MathResult<SingularValueDecomposition> {
+        SingularValueDecomposition::new(self)
+    }
+
+    pub fn rank(&self) -> MathResult<usize> {
+        Ok(self.svd()?.rank())
+    }
+
+    /// ratio of the largest and the smallest singular values, infinite for singular matrices
+    pub fn condition_number(&self) -> MathResult<f64> {
+        let svd = self.svd()?;
+        let values = svd.singular_values();
+        match (values.first(), values.last()) {
+            (Some(largest), Some(smallest)) if *smallest > svd.tolerance() => Ok(largest / smallest),
+            (Some(_), Some(_)) => Ok(f64::INFINITY),
+            _ => Ok(0.0),
+        }
+    }
+
+    /// the largest singular value
+    pub fn spectral_norm(&self) -> MathResult<f64> {
+        Ok(self.svd()?.singular_values().first().copied().unwrap_or(0.0))
+    }
+
+    pub fn pseudo_inverse(&self) -> MathResult<Matrix> {
+        Ok(self.svd()?.pseudo_inverse())
+    }
+}
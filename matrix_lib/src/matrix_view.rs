@@ -0,0 +1,353 @@
+use std::borrow::Cow;
+
+use super::dimensions::Dimensions;
+use super::errors::*;
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+/// Position of a view's elements in the borrowed content
+#[derive(Copy, Clone, Debug)]
+struct Layout {
+    dimensions: Dimensions,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl Layout {
+    /// strides of a single row or column don't matter, they are normalized to the row-major ones
+    fn new(rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> Self {
+        Layout {
+            dimensions: Dimensions::new(rows, cols),
+            row_stride: if rows > 1 { row_stride } else { cols },
+            col_stride: if cols > 1 { col_stride } else { 1 },
+        }
+    }
+
+    fn row_major(rows: usize, cols: usize) -> Self {
+        Self::new(rows, cols, cols, 1)
+    }
+
+    #[inline(always)]
+    fn position(&self, row: usize, col: usize) -> usize {
+        row * self.row_stride + col * self.col_stride
+    }
+
+    fn is_contiguous(&self) -> bool {
+        self.col_stride == 1 && self.row_stride == self.dimensions.cols
+    }
+
+    fn transpose(&self) -> Self {
+        Self::new(self.dimensions.cols, self.dimensions.rows, self.col_stride, self.row_stride)
+    }
+
+    /// offset of the first element and layout of a sub-block
+    fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> MathResult<(usize, Layout)> {
+        let dims = self.dimensions;
+        if rows <= dims.rows && row <= dims.rows - rows && cols <= dims.cols && col <= dims.cols - cols {
+            Ok((self.position(row, col), Self::new(rows, cols, self.row_stride, self.col_stride)))
+        } else {
+            Err(MathError::IncorrectBlock(row, col, Dimensions::new(rows, cols), dims))
+        }
+    }
+}
+
+/// Borrowed, possibly strided, window into a matrix
+#[derive(Copy, Clone)]
+pub struct MatrixView<'a, T: Scalar = f64> {
+    content: &'a [T],
+    layout: Layout,
+}
+
+/// Mutable counterpart of `MatrixView`, writes go directly to the borrowed matrix
+pub struct MatrixViewMut<'a, T: Scalar = f64> {
+    content: &'a mut [T],
+    layout: Layout,
+}
+
+/// Read access to the elements as a view, implemented by matrices and views
+pub trait AsMatrixView<T: Scalar> {
+    fn as_view(&self) -> MatrixView<'_, T>;
+}
+
+impl<T: Scalar> AsMatrixView<T> for Matrix<T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        self.view()
+    }
+}
+
+impl<T: Scalar> AsMatrixView<T> for MatrixView<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        *self
+    }
+}
+
+impl<T: Scalar> AsMatrixView<T> for MatrixViewMut<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        self.view()
+    }
+}
+
+/// slices content from the offset, empty blocks may start right past the end
+fn tail<T>(content: &[T], offset: usize) -> &[T] {
+    &content[offset.min(content.len())..]
+}
+
+fn tail_mut<T>(content: &mut [T], offset: usize) -> &mut [T] {
+    let offset = offset.min(content.len());
+    &mut content[offset..]
+}
+
+impl<T: Scalar> Matrix<T> {
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            content: self.as_slice(),
+            layout: Layout::row_major(self.rows(), self.cols()),
+        }
+    }
+
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let layout = Layout::row_major(self.rows(), self.cols());
+        MatrixViewMut {
+            content: self.as_mut_slice(),
+            layout,
+        }
+    }
+
+    /// `rows x cols` sub-block with the top left corner at `row:col`
+    pub fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> MathResult<MatrixView<'_, T>> {
+        self.view().block(row, col, rows, cols)
+    }
+
+    pub fn block_mut(&mut self, row: usize, col: usize, rows: usize, cols: usize) -> MathResult<MatrixViewMut<'_, T>> {
+        self.view_mut().into_block(row, col, rows, cols)
+    }
+
+    /// single row as `1 x cols` view
+    pub fn row_view(&self, row: usize) -> MathResult<MatrixView<'_, T>> {
+        self.view().row_view(row)
+    }
+
+    /// single column as `rows x 1` view
+    pub fn col_view(&self, col: usize) -> MathResult<MatrixView<'_, T>> {
+        self.view().col_view(col)
+    }
+
+    /// transposed matrix without copying the content
+    pub fn transpose_view(&self) -> MatrixView<'_, T> {
+        self.view().transpose()
+    }
+}
+
+impl<'a, T: Scalar> MatrixView<'a, T> {
+    #[inline(always)]
+    pub fn dimensions(&self) -> Dimensions {
+        self.layout.dimensions
+    }
+
+    #[inline(always)]
+    pub fn rows(&self) -> usize {
+        self.layout.dimensions.rows
+    }
+
+    #[inline(always)]
+    pub fn cols(&self) -> usize {
+        self.layout.dimensions.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> MathResult<T> {
+        if self.layout.dimensions.is_valid_position(row, col) {
+            Ok(self.get_unchecked(row, col))
+        } else {
+            Err(MathError::IncorrectPosition(row, col))
+        }
+    }
+
+    #[inline(always)]
+    pub fn get_unchecked(&self, row: usize, col: usize) -> T {
+        self.content[self.layout.position(row, col)]
+    }
+
+    pub fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> MathResult<MatrixView<'a, T>> {
+        let (offset, layout) = self.layout.block(row, col, rows, cols)?;
+        Ok(MatrixView {
+            content: tail(self.content, offset),
+            layout,
+        })
+    }
+
+    pub fn row_view(&self, row: usize) -> MathResult<MatrixView<'a, T>> {
+        self.block(row, 0, 1, self.cols())
+    }
+
+    pub fn col_view(&self, col: usize) -> MathResult<MatrixView<'a, T>> {
+        self.block(0, col, self.rows(), 1)
+    }
+
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            content: self.content,
+            layout: self.layout.transpose(),
+        }
+    }
+
+    /// view repeating a single row or column up to the given dimensions
+    pub fn broadcast(&self, dimensions: Dimensions) -> MathResult<MatrixView<'a, T>> {
+        let own = self.dimensions();
+        if own.broadcast(&dimensions) != Some(dimensions) {
+            return Err(MathError::IncompatibleBroadcast("broadcast".to_string(), own, dimensions));
+        }
+        let row_stride = if own.rows == dimensions.rows { self.layout.row_stride } else { 0 };
+        let col_stride = if own.cols == dimensions.cols { self.layout.col_stride } else { 0 };
+        Ok(MatrixView {
+            content: self.content,
+            layout: Layout::new(dimensions.rows, dimensions.cols, row_stride, col_stride),
+        })
+    }
+
+    /// row-major content if the view has no gaps between elements
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        if self.layout.is_contiguous() {
+            Some(&self.content[..self.layout.dimensions.size()])
+        } else {
+            None
+        }
+    }
+
+    /// elements of a row, copied only if they aren't adjacent
+    pub fn row_content(&self, row: usize) -> Cow<'a, [T]> {
+        let cols = self.cols();
+        if cols == 0 {
+            Cow::Borrowed(&[])
+        } else if self.layout.col_stride == 1 {
+            let start = self.layout.position(row, 0);
+            Cow::Borrowed(&self.content[start..start + cols])
+        } else {
+            Cow::Owned((0..cols).map(|j| self.get_unchecked(row, j)).collect())
+        }
+    }
+
+    /// content starting at the first element with row and column strides
+    pub(crate) fn strided(&self) -> (&'a [T], usize, usize) {
+        (self.content, self.layout.row_stride, self.layout.col_stride)
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        match self.as_slice() {
+            Some(content) => Matrix::from_content(self.rows(), self.cols(), content.to_vec()),
+            None => Matrix::new(self.rows(), self.cols(), |i, j| self.get_unchecked(i, j)),
+        }
+    }
+}
+
+impl<'a, T: Scalar> MatrixViewMut<'a, T> {
+    #[inline(always)]
+    pub fn dimensions(&self) -> Dimensions {
+        self.layout.dimensions
+    }
+
+    #[inline(always)]
+    pub fn rows(&self) -> usize {
+        self.layout.dimensions.rows
+    }
+
+    #[inline(always)]
+    pub fn cols(&self) -> usize {
+        self.layout.dimensions.cols
+    }
+
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            content: self.content,
+            layout: self.layout,
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> MathResult<T> {
+        self.view().get(row, col)
+    }
+
+    #[inline(always)]
+    pub fn get_unchecked(&self, row: usize, col: usize) -> T {
+        self.content[self.layout.position(row, col)]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> MathResult<()> {
+        if self.layout.dimensions.is_valid_position(row, col) {
+            self.set_unchecked(row, col, value);
+            Ok(())
+        } else {
+            Err(MathError::IncorrectPosition(row, col))
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_unchecked(&mut self, row: usize, col: usize, value: T) {
+        let pos = self.layout.position(row, col);
+        self.content[pos] = value;
+    }
+
+    pub fn block_mut(&mut self, row: usize, col: usize, rows: usize, cols: usize) -> MathResult<MatrixViewMut<'_, T>> {
+        let (offset, layout) = self.layout.block(row, col, rows, cols)?;
+        Ok(MatrixViewMut {
+            content: tail_mut(self.content, offset),
+            layout,
+        })
+    }
+
+    /// same as `block_mut` but keeps the original borrow
+    pub fn into_block(self, row: usize, col: usize, rows: usize, cols: usize) -> MathResult<MatrixViewMut<'a, T>> {
+        let (offset, layout) = self.layout.block(row, col, rows, cols)?;
+        Ok(MatrixViewMut {
+            content: tail_mut(self.content, offset),
+            layout,
+        })
+    }
+
+    pub fn row_view_mut(&mut self, row: usize) -> MathResult<MatrixViewMut<'_, T>> {
+        let cols = self.cols();
+        self.block_mut(row, 0, 1, cols)
+    }
+
+    pub fn col_view_mut(&mut self, col: usize) -> MathResult<MatrixViewMut<'_, T>> {
+        let rows = self.rows();
+        self.block_mut(0, col, rows, 1)
+    }
+
+    pub fn transpose(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            content: self.content,
+            layout: self.layout.transpose(),
+        }
+    }
+
+    pub fn modify<Op: Fn(T) -> T>(&mut self, operation: Op) {
+        for i in 0..self.rows() {
+            for j in 0..self.cols() {
+                let pos = self.layout.position(i, j);
+                self.content[pos] = operation(self.content[pos]);
+            }
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.modify(|_| value);
+    }
+
+    /// copies elements of a matrix or view of the same size
+    pub fn assign(&mut self, other: &impl AsMatrixView<T>) -> MathResult<()> {
+        let other = other.as_view();
+        if self.dimensions() != other.dimensions() {
+            return Err(MathError::IncorrectMatricesDimensions("assign".to_string(), self.dimensions(), other.dimensions()));
+        }
+        for i in 0..self.rows() {
+            for j in 0..self.cols() {
+                self.set_unchecked(i, j, other.get_unchecked(i, j));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.view().to_matrix()
+    }
+}
@@ -0,0 +1,145 @@
+use super::simd::Kernels;
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Element type of `Matrix`, implemented for `f32` and `f64`.
+/// Decompositions, spectral functions and random constructors are `f64` only
+pub trait Scalar:
+    Copy
+    + Default
+    + PartialEq
+    + PartialOrd
+    + fmt::Debug
+    + fmt::Display
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + for<'a> Sum<&'a Self>
+    + Kernels
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// machine epsilon
+    const EPSILON: Self;
+
+    /// nearest representable value
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    /// `exp(x) - 1`, accurate near zero
+    fn exp_m1(self) -> Self;
+    fn ln(self) -> Self;
+    /// `ln(1 + x)`, accurate near zero
+    fn ln_1p(self) -> Self;
+    fn tanh(self) -> Self;
+    fn powi(self, power: i32) -> Self;
+    fn powf(self, power: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn signum(self) -> Self;
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! scalar_impl {
+    ($t:ident) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = $t::EPSILON;
+
+            #[inline(always)]
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            #[inline(always)]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[inline(always)]
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            #[inline(always)]
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            #[inline(always)]
+            fn exp_m1(self) -> Self {
+                $t::exp_m1(self)
+            }
+
+            #[inline(always)]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            #[inline(always)]
+            fn ln_1p(self) -> Self {
+                $t::ln_1p(self)
+            }
+
+            #[inline(always)]
+            fn tanh(self) -> Self {
+                $t::tanh(self)
+            }
+
+            #[inline(always)]
+            fn powi(self, power: i32) -> Self {
+                $t::powi(self, power)
+            }
+
+            #[inline(always)]
+            fn powf(self, power: Self) -> Self {
+                $t::powf(self, power)
+            }
+
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            #[inline(always)]
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+
+            #[inline(always)]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+        }
+    };
+}
+
+scalar_impl!(f32);
+scalar_impl!(f64);
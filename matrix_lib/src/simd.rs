@@ -0,0 +1,442 @@
+//! Vectorized kernels over `f32` and `f64` slices.
+//! AVX is used on x86_64 when the CPU supports it (checked at runtime),
+//! otherwise the scalar loops are used. All slices passed to a kernel must have the same length.
+
+use std::mem::MaybeUninit;
+
+/// Element types with vectorized slice kernels, see the free functions of this module
+pub trait Kernels: Sized {
+    fn add(a: &[Self], b: &[Self]) -> Vec<Self>;
+    fn sub(a: &[Self], b: &[Self]) -> Vec<Self>;
+    fn mul(a: &[Self], b: &[Self]) -> Vec<Self>;
+    fn div(a: &[Self], b: &[Self]) -> Vec<Self>;
+    fn add_assign(a: &mut [Self], b: &[Self]);
+    fn sub_assign(a: &mut [Self], b: &[Self]);
+    fn mul_assign(a: &mut [Self], b: &[Self]);
+    fn div_assign(a: &mut [Self], b: &[Self]);
+    fn add_scalar(a: &mut [Self], value: Self);
+    fn mul_scalar(a: &mut [Self], value: Self);
+    fn div_scalar(a: &mut [Self], value: Self);
+    fn sum(a: &[Self]) -> Self;
+    fn dot(a: &[Self], b: &[Self]) -> Self;
+}
+
+/// element-wise `a + b` as a new vector
+pub fn add<T: Kernels>(a: &[T], b: &[T]) -> Vec<T> {
+    T::add(a, b)
+}
+
+pub fn sub<T: Kernels>(a: &[T], b: &[T]) -> Vec<T> {
+    T::sub(a, b)
+}
+
+pub fn mul<T: Kernels>(a: &[T], b: &[T]) -> Vec<T> {
+    T::mul(a, b)
+}
+
+pub fn div<T: Kernels>(a: &[T], b: &[T]) -> Vec<T> {
+    T::div(a, b)
+}
+
+/// element-wise `a += b`
+pub fn add_assign<T: Kernels>(a: &mut [T], b: &[T]) {
+    T::add_assign(a, b)
+}
+
+pub fn sub_assign<T: Kernels>(a: &mut [T], b: &[T]) {
+    T::sub_assign(a, b)
+}
+
+pub fn mul_assign<T: Kernels>(a: &mut [T], b: &[T]) {
+    T::mul_assign(a, b)
+}
+
+pub fn div_assign<T: Kernels>(a: &mut [T], b: &[T]) {
+    T::div_assign(a, b)
+}
+
+/// `a += value` for each element
+pub fn add_scalar<T: Kernels>(a: &mut [T], value: T) {
+    T::add_scalar(a, value)
+}
+
+pub fn mul_scalar<T: Kernels>(a: &mut [T], value: T) {
+    T::mul_scalar(a, value)
+}
+
+pub fn div_scalar<T: Kernels>(a: &mut [T], value: T) {
+    T::div_scalar(a, value)
+}
+
+pub fn sum<T: Kernels>(a: &[T]) -> T {
+    T::sum(a)
+}
+
+pub fn dot<T: Kernels>(a: &[T], b: &[T]) -> T {
+    T::dot(a, b)
+}
+
+/// runs the kernel over the spare capacity of a new vector, so the result isn't zeroed first
+fn collect_binary<T, K: FnOnce(&[T], &[T], &mut [MaybeUninit<T>])>(a: &[T], b: &[T], kernel: K) -> Vec<T> {
+    assert!(a.len() == b.len(), "simd kernel: slices of different length");
+    let mut out = Vec::with_capacity(a.len());
+    kernel(a, b, &mut out.spare_capacity_mut()[..a.len()]);
+    // SAFETY: every kernel initializes all a.len() elements
+    unsafe { out.set_len(a.len()) };
+    out
+}
+
+/// calls AVX kernel when it's supported and the scalar one otherwise
+macro_rules! dispatch {
+    ($module:ident::$name:ident($($arg:expr),*)) => {{
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx") {
+            // SAFETY: AVX support is checked above
+            return unsafe { $module::avx::$name($($arg),*) };
+        }
+        $module::scalar::$name($($arg),*)
+    }};
+}
+
+/// defines every element-wise kernel through the macros passed as the arguments
+macro_rules! elementwise_kernels {
+    ($binary:ident, $assign:ident, $scalar:ident) => {
+        $binary!(add, add, +);
+        $binary!(sub, sub, -);
+        $binary!(mul, mul, *);
+        $binary!(div, div, /);
+        $assign!(add_assign, add, +);
+        $assign!(sub_assign, sub, -);
+        $assign!(mul_assign, mul, *);
+        $assign!(div_assign, div, /);
+        $scalar!(add_scalar, add, +);
+        $scalar!(mul_scalar, mul, *);
+        $scalar!(div_scalar, div, /);
+    };
+}
+
+/// kernels of a single element type, `LANES` elements fit into 256-bit register
+macro_rules! typed_kernels {
+    (
+        $module:ident, $t:ident, $lanes:expr,
+        load: $load:ident, store: $store:ident, splat: $splat:ident, zero: $zero:ident,
+        add: $add:ident, sub: $sub:ident, mul: $mul:ident, div: $div:ident
+    ) => {
+        pub(crate) mod $module {
+            const LANES: usize = $lanes;
+
+            /// pairwise sum of the accumulator lanes, the same order in both implementations
+            fn reduce(mut lanes: [$t; LANES]) -> $t {
+                let mut width = LANES;
+                while width > 1 {
+                    width /= 2;
+                    for i in 0..width {
+                        lanes[i] += lanes[i + width];
+                    }
+                }
+                lanes[0]
+            }
+
+            /// portable fallback, the loops are simple enough to be vectorized by compiler for the baseline target
+            pub(crate) mod scalar {
+                use super::{reduce, LANES};
+                use std::mem::MaybeUninit;
+
+                macro_rules! binary {
+                    ($name:ident, $op_name:ident, $op:tt) => {
+                        pub fn $name(a: &[$t], b: &[$t], out: &mut [MaybeUninit<$t>]) {
+                            for ((o, x), y) in out.iter_mut().zip(a).zip(b) {
+                                o.write(x $op y);
+                            }
+                        }
+                    };
+                }
+
+                macro_rules! assign {
+                    ($name:ident, $op_name:ident, $op:tt) => {
+                        pub fn $name(a: &mut [$t], b: &[$t]) {
+                            for (x, y) in a.iter_mut().zip(b) {
+                                let result = *x $op y;
+                                *x = result;
+                            }
+                        }
+                    };
+                }
+
+                macro_rules! scalar {
+                    ($name:ident, $op_name:ident, $op:tt) => {
+                        pub fn $name(a: &mut [$t], value: $t) {
+                            for x in a.iter_mut() {
+                                let result = *x $op value;
+                                *x = result;
+                            }
+                        }
+                    };
+                }
+
+                elementwise_kernels!(binary, assign, scalar);
+
+                /// independent accumulators per lane, the same reduction order as the vector kernel
+                pub fn sum(a: &[$t]) -> $t {
+                    let mut acc = [0.0; LANES];
+                    let chunks = a.chunks_exact(LANES);
+                    let tail: $t = chunks.remainder().iter().sum();
+                    for chunk in chunks {
+                        for (s, x) in acc.iter_mut().zip(chunk) {
+                            *s += x;
+                        }
+                    }
+                    reduce(acc) + tail
+                }
+
+                pub fn dot(a: &[$t], b: &[$t]) -> $t {
+                    let mut acc = [0.0; LANES];
+                    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+                    let tail: $t = a_chunks.remainder().iter().zip(b_chunks.remainder()).map(|(x, y)| x * y).sum();
+                    for (x, y) in a_chunks.zip(b_chunks) {
+                        for k in 0..LANES {
+                            acc[k] += x[k] * y[k];
+                        }
+                    }
+                    reduce(acc) + tail
+                }
+            }
+
+            /// 256-bit kernels, must only be called when AVX is available
+            #[cfg(target_arch = "x86_64")]
+            pub(crate) mod avx {
+                use super::{reduce, LANES};
+                use std::arch::x86_64::*;
+                use std::mem::MaybeUninit;
+
+                macro_rules! intrinsic {
+                    (add) => { $add };
+                    (sub) => { $sub };
+                    (mul) => { $mul };
+                    (div) => { $div };
+                }
+
+                macro_rules! binary {
+                    ($name:ident, $op_name:ident, $op:tt) => {
+                        #[target_feature(enable = "avx")]
+                        pub unsafe fn $name(a: &[$t], b: &[$t], out: &mut [MaybeUninit<$t>]) {
+                            let n = a.len() / LANES * LANES;
+                            for i in (0..n).step_by(LANES) {
+                                let x = $load(a.as_ptr().add(i));
+                                let y = $load(b.as_ptr().add(i));
+                                $store(out.as_mut_ptr().add(i).cast(), intrinsic!($op_name)(x, y));
+                            }
+                            super::scalar::$name(&a[n..], &b[n..], &mut out[n..]);
+                        }
+                    };
+                }
+
+                macro_rules! assign {
+                    ($name:ident, $op_name:ident, $op:tt) => {
+                        #[target_feature(enable = "avx")]
+                        pub unsafe fn $name(a: &mut [$t], b: &[$t]) {
+                            let n = a.len() / LANES * LANES;
+                            for i in (0..n).step_by(LANES) {
+                                let x = $load(a.as_ptr().add(i));
+                                let y = $load(b.as_ptr().add(i));
+                                $store(a.as_mut_ptr().add(i), intrinsic!($op_name)(x, y));
+                            }
+                            super::scalar::$name(&mut a[n..], &b[n..]);
+                        }
+                    };
+                }
+
+                macro_rules! scalar {
+                    ($name:ident, $op_name:ident, $op:tt) => {
+                        #[target_feature(enable = "avx")]
+                        pub unsafe fn $name(a: &mut [$t], value: $t) {
+                            let n = a.len() / LANES * LANES;
+                            let y = $splat(value);
+                            for i in (0..n).step_by(LANES) {
+                                let x = $load(a.as_ptr().add(i));
+                                $store(a.as_mut_ptr().add(i), intrinsic!($op_name)(x, y));
+                            }
+                            super::scalar::$name(&mut a[n..], value);
+                        }
+                    };
+                }
+
+                elementwise_kernels!(binary, assign, scalar);
+
+                #[target_feature(enable = "avx")]
+                pub unsafe fn sum(a: &[$t]) -> $t {
+                    let n = a.len() / LANES * LANES;
+                    let mut acc = $zero();
+                    for i in (0..n).step_by(LANES) {
+                        acc = $add(acc, $load(a.as_ptr().add(i)));
+                    }
+                    let mut lanes = [0.0; LANES];
+                    $store(lanes.as_mut_ptr(), acc);
+                    reduce(lanes) + a[n..].iter().sum::<$t>()
+                }
+
+                #[target_feature(enable = "avx")]
+                pub unsafe fn dot(a: &[$t], b: &[$t]) -> $t {
+                    let n = a.len() / LANES * LANES;
+                    let mut acc = $zero();
+                    for i in (0..n).step_by(LANES) {
+                        let x = $load(a.as_ptr().add(i));
+                        let y = $load(b.as_ptr().add(i));
+                        acc = $add(acc, $mul(x, y));
+                    }
+                    let mut lanes = [0.0; LANES];
+                    $store(lanes.as_mut_ptr(), acc);
+                    reduce(lanes) + a[n..].iter().zip(&b[n..]).map(|(x, y)| x * y).sum::<$t>()
+                }
+            }
+        }
+
+        impl Kernels for $t {
+            fn add(a: &[$t], b: &[$t]) -> Vec<$t> {
+                collect_binary(a, b, |a, b, out| dispatch!($module::add(a, b, out)))
+            }
+
+            fn sub(a: &[$t], b: &[$t]) -> Vec<$t> {
+                collect_binary(a, b, |a, b, out| dispatch!($module::sub(a, b, out)))
+            }
+
+            fn mul(a: &[$t], b: &[$t]) -> Vec<$t> {
+                collect_binary(a, b, |a, b, out| dispatch!($module::mul(a, b, out)))
+            }
+
+            fn div(a: &[$t], b: &[$t]) -> Vec<$t> {
+                collect_binary(a, b, |a, b, out| dispatch!($module::div(a, b, out)))
+            }
+
+            fn add_assign(a: &mut [$t], b: &[$t]) {
+                assert!(a.len() == b.len(), "simd kernel: slices of different length");
+                dispatch!($module::add_assign(a, b))
+            }
+
+            fn sub_assign(a: &mut [$t], b: &[$t]) {
+                assert!(a.len() == b.len(), "simd kernel: slices of different length");
+                dispatch!($module::sub_assign(a, b))
+            }
+
+            fn mul_assign(a: &mut [$t], b: &[$t]) {
+                assert!(a.len() == b.len(), "simd kernel: slices of different length");
+                dispatch!($module::mul_assign(a, b))
+            }
+
+            fn div_assign(a: &mut [$t], b: &[$t]) {
+                assert!(a.len() == b.len(), "simd kernel: slices of different length");
+                dispatch!($module::div_assign(a, b))
+            }
+
+            fn add_scalar(a: &mut [$t], value: $t) {
+                dispatch!($module::add_scalar(a, value))
+            }
+
+            fn mul_scalar(a: &mut [$t], value: $t) {
+                dispatch!($module::mul_scalar(a, value))
+            }
+
+            fn div_scalar(a: &mut [$t], value: $t) {
+                dispatch!($module::div_scalar(a, value))
+            }
+
+            fn sum(a: &[$t]) -> $t {
+                dispatch!($module::sum(a))
+            }
+
+            fn dot(a: &[$t], b: &[$t]) -> $t {
+                assert!(a.len() == b.len(), "simd kernel: slices of different length");
+                dispatch!($module::dot(a, b))
+            }
+        }
+    };
+}
+
+typed_kernels!(
+    double, f64, 4,
+    load: _mm256_loadu_pd, store: _mm256_storeu_pd, splat: _mm256_set1_pd, zero: _mm256_setzero_pd,
+    add: _mm256_add_pd, sub: _mm256_sub_pd, mul: _mm256_mul_pd, div: _mm256_div_pd
+);
+
+typed_kernels!(
+    single, f32, 8,
+    load: _mm256_loadu_ps, store: _mm256_storeu_ps, splat: _mm256_set1_ps, zero: _mm256_setzero_ps,
+    add: _mm256_add_ps, sub: _mm256_sub_ps, mul: _mm256_mul_ps, div: _mm256_div_ps
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Binary<T> = fn(&[T], &[T]) -> Vec<T>;
+    type Assign<T> = fn(&mut [T], &[T]);
+    type ScalarAssign<T> = fn(&mut [T], T);
+
+    /// compares dispatched kernels of the type with its scalar fallback
+    macro_rules! check_kernels {
+        ($t:ident, $module:ident) => {{
+            let sample = |len: usize, shift: f64| -> Vec<$t> {
+                (0..len).map(|i| ((i as f64 * 0.37 + shift).sin() + 1.5) as $t).collect()
+            };
+            // lengths around multiples of the vector width to cover the tails
+            for len in 0..35 {
+                let (a, b) = (sample(len, 0.1), sample(len, 2.3));
+                let binary: [(Binary<$t>, fn($t, $t) -> $t); 4] = [
+                    (add, |x, y| x + y),
+                    (sub, |x, y| x - y),
+                    (mul, |x, y| x * y),
+                    (div, |x, y| x / y),
+                ];
+                for (kernel, op) in binary {
+                    let expected: Vec<$t> = a.iter().zip(&b).map(|(x, y)| op(*x, *y)).collect();
+                    assert_eq!(kernel(&a, &b), expected);
+                }
+                let assign: [(Assign<$t>, Assign<$t>); 4] = [
+                    (add_assign, $module::scalar::add_assign),
+                    (sub_assign, $module::scalar::sub_assign),
+                    (mul_assign, $module::scalar::mul_assign),
+                    (div_assign, $module::scalar::div_assign),
+                ];
+                for (fast, slow) in assign {
+                    let (mut x, mut y) = (a.clone(), a.clone());
+                    fast(&mut x, &b);
+                    slow(&mut y, &b);
+                    assert_eq!(x, y);
+                }
+                let scalar: [(ScalarAssign<$t>, ScalarAssign<$t>); 3] = [
+                    (add_scalar, $module::scalar::add_scalar),
+                    (mul_scalar, $module::scalar::mul_scalar),
+                    (div_scalar, $module::scalar::div_scalar),
+                ];
+                for (fast, slow) in scalar {
+                    let (mut x, mut y) = (a.clone(), a.clone());
+                    fast(&mut x, 1.7);
+                    slow(&mut y, 1.7);
+                    assert_eq!(x, y);
+                }
+                assert_eq!(sum(&a), $module::scalar::sum(&a));
+                assert_eq!(dot(&a, &b), $module::scalar::dot(&a, &b));
+            }
+        }};
+    }
+
+    #[test]
+    fn simd_kernels_match_scalar() {
+        check_kernels!(f64, double);
+        check_kernels!(f32, single);
+    }
+
+    #[test]
+    fn simd_reductions() {
+        let a: Vec<f64> = (0..1001).map(|i| (i as f64 * 0.37).sin() + 1.5).collect();
+        let b: Vec<f64> = (0..1001).map(|i| (i as f64 * 0.37 + 1.0).sin() + 1.5).collect();
+        let expected_sum: f64 = a.iter().sum();
+        let expected_dot: f64 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+        assert!((sum(&a) - expected_sum).abs() < 1e-10);
+        assert!((dot(&a, &b) - expected_dot).abs() < 1e-10);
+        assert_eq!(sum::<f32>(&[]), 0.0);
+
+        let single: Vec<f32> = a.iter().map(|x| *x as f32).collect();
+        assert!((sum(&single) as f64 - expected_sum).abs() < 1e-2);
+    }
+}
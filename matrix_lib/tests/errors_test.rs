@@ -0,0 +1,51 @@
+extern crate matrix_lib;
+
+use std::error::Error;
+
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+};
+
+fn parse_vector(text: &str) -> Result<Matrix, Box<dyn Error>> {
+    let values = text
+        .split_whitespace()
+        .map(|x| x.parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Matrix::vector(&values)?.product(&Matrix::identity(2))?)
+}
+
+#[test]
+fn math_error_is_std_error() {
+    let error = parse_vector("1 2 3").unwrap_err();
+    assert!(error.downcast_ref::<MathError>().is_some(), "MathError should be boxed as std error");
+    assert_eq!(error.to_string(), MathError::IncorrectMatricesDimensions(
+        "product".to_string(),
+        Matrix::<f64>::zero(3, 1).dimensions(),
+        Matrix::<f64>::zero(2, 2).dimensions(),
+    ).description());
+    assert!(parse_vector("1 x").is_err());
+}
+
+#[test]
+fn math_error_conversions() {
+    let parse: MathError = "x".parse::<f64>().unwrap_err().into();
+    assert!(matches!(parse, MathError::Parse(_)));
+    let parse: MathError = "-".parse::<usize>().unwrap_err().into();
+    assert!(matches!(parse, MathError::Parse(_)));
+
+    let io: MathError = std::fs::read("/nonexistent/matrix").unwrap_err().into();
+    assert!(matches!(&io, MathError::Io(error) if error.kind() == std::io::ErrorKind::NotFound));
+    assert!(format!("{}", io).starts_with("IO error"));
+    let source = io.source().expect("IO error should be the source");
+    assert!(source.downcast_ref::<std::io::Error>().is_some());
+}
+
+#[test]
+fn math_error_layer_source() {
+    let error = MathError::SingularMatrix("solve".to_string()).in_layer(3);
+    assert_eq!(error.to_string(), "Layer 3: Can't perform operation 'solve' with singular matrix");
+    let source = error.source().expect("Layer error should have the source");
+    assert_eq!(source.to_string(), MathError::SingularMatrix("solve".to_string()).to_string());
+    assert!(source.source().is_none());
+}
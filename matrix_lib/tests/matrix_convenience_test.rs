@@ -70,14 +70,14 @@ fn matrix_utils_random() {
     for i in 0..m.rows() {
         for j in 0..m.cols() {
             let v = m[i][j];
-            assert!(v >= 0.0 && v < 1.0, "Incorrect initial random value")
+            assert!((0.0..1.0).contains(&v), "Incorrect initial random value")
         }
     }
 }
 
 #[test]
 fn matrix_init_zero_matrix() {
-    let matrix = Matrix::zero(2, 2);
+    let matrix: Matrix = Matrix::zero(2, 2);
     let zero_bits = 0.0f64.to_bits();
     for i in 0..matrix.rows() {
         for j in 0..matrix.cols() {
@@ -115,4 +115,26 @@ fn matrix_init_from_incorrect_vector() -> MathResult<()> {
     ];
     assert_eq!(Matrix::from_vector(&v), Err(MathError::IncorrectVectorDimensions));
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn matrix_utils_random_seeded() {
+    let a = Matrix::random_seeded(10, 20, 17);
+    let b = Matrix::random_seeded(10, 20, 17);
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            assert_eq!(a[i][j].to_bits(), b[i][j].to_bits(), "Matrices with the same seed should be identical");
+            assert!((0.0..1.0).contains(&a[i][j]), "Incorrect initial random value");
+        }
+    }
+    assert!(a != Matrix::random_seeded(10, 20, 18), "Matrices with different seeds should differ");
+}
+
+#[test]
+fn matrix_reshape() -> MathResult<()> {
+    let m: Matrix = Matrix::new(2, 3, |i, j| (i * 3 + j) as f64);
+    let reshaped = m.clone().reshape(3, 2)?;
+    assert_eq!(reshaped, Matrix::new(3, 2, |i, j| (i * 2 + j) as f64), "Reshape should keep row-major order");
+    assert!(m.reshape(4, 2).is_err(), "Reshape should keep the number of elements");
+    Ok(())
+}
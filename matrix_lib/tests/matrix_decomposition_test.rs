@@ -0,0 +1,229 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    dimensions::Dimensions,
+    errors::*,
+    matrix::Matrix,
+    matrix_decomposition::*,
+};
+
+fn assert_close(actual: &Matrix, expected: &Matrix, eps: f64, message: &str) -> MathResult<()> {
+    assert_eq!(actual.dimensions(), expected.dimensions(), "{}", message);
+    let error = actual.sub(expected)?.map(f64::abs);
+    for i in 0..error.rows() {
+        for j in 0..error.cols() {
+            assert!(error[i][j] < eps, "{}: actual {:?}, expected {:?}", message, actual, expected);
+        }
+    }
+    Ok(())
+}
+
+fn sample_matrix() -> MathResult<Matrix> {
+    Matrix::from_vector(&[
+        vec![2.0, 1.0, 1.0, 0.0],
+        vec![4.0, 3.0, 3.0, 1.0],
+        vec![8.0, 7.0, 9.0, 5.0],
+        vec![6.0, 7.0, 9.0, 8.0],
+    ])
+}
+
+#[test]
+fn matrix_lu_reconstruction() -> MathResult<()> {
+    let a = sample_matrix()?;
+    let lu = a.lu()?;
+    let l = lu.l();
+    let u = lu.u();
+    for i in 0..a.rows() {
+        assert_eq!(l[i][i], 1.0, "L should have unit diagonal");
+        for j in 0..a.cols() {
+            if i < j {
+                assert_eq!(l[i][j], 0.0, "L should be lower triangular");
+            } else if i > j {
+                assert_eq!(u[i][j], 0.0, "U should be upper triangular");
+            }
+        }
+    }
+    assert_close(&l.product(&u)?, &lu.p().product(&a)?, 1e-12, "P * A should be equal to L * U")
+}
+
+#[test]
+fn matrix_determinant() -> MathResult<()> {
+    let a = Matrix::from_vector(&[
+        vec![0.0, 2.0],
+        vec![3.0, 4.0],
+    ])?;
+    assert!((a.determinant()? + 6.0).abs() < 1e-12, "Determinant should respect row swaps");
+    assert!((sample_matrix()?.determinant()? - 8.0).abs() < 1e-10);
+    assert!((Matrix::identity(5).determinant()? - 1.0).abs() < 1e-12);
+
+    let singular = Matrix::from_vector(&[
+        vec![1.0, 2.0],
+        vec![2.0, 4.0],
+    ])?;
+    assert!(singular.determinant()?.abs() < 1e-12, "Singular matrix determinant should be 0");
+    Ok(())
+}
+
+#[test]
+fn matrix_inverse() -> MathResult<()> {
+    let a = sample_matrix()?;
+    let inverse = a.inverse()?;
+    assert_close(&a.product(&inverse)?, &Matrix::identity(4), 1e-12, "A * inv(A) should be identity")?;
+    assert_close(&inverse.product(&a)?, &Matrix::identity(4), 1e-12, "inv(A) * A should be identity")
+}
+
+#[test]
+fn matrix_solve() -> MathResult<()> {
+    let a = sample_matrix()?;
+    let x = Matrix::from_vector(&[
+        vec![1.0, -1.0],
+        vec![2.0, 0.5],
+        vec![-3.0, 2.0],
+        vec![0.5, 1.0],
+    ])?;
+    let b = a.product(&x)?;
+    assert_close(&a.solve(&b)?, &x, 1e-10, "Solution of A * X = B is incorrect")?;
+    assert!(a.solve(&Matrix::zero(3, 1)).is_err(), "Right side with wrong rows count should be rejected");
+    Ok(())
+}
+
+#[test]
+fn matrix_singular_and_non_square() -> MathResult<()> {
+    let singular = Matrix::from_vector(&[
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0],
+    ])?;
+    assert!(matches!(singular.inverse(), Err(MathError::SingularMatrix(_))), "Singular matrix can't be inverted");
+    assert!(matches!(singular.solve(&Matrix::zero(3, 1)), Err(MathError::SingularMatrix(_))));
+
+    let rectangular = Matrix::zero(2, 3);
+    assert_eq!(rectangular.determinant(), Err(MathError::NotSquareMatrix("lu".to_string(), rectangular.dimensions())));
+    assert!(rectangular.inverse().is_err());
+    Ok(())
+}
+
+#[test]
+fn matrix_qr_reconstruction() -> MathResult<()> {
+    let tall = Matrix::from_vector(&[
+        vec![12.0, -51.0, 4.0],
+        vec![6.0, 167.0, -68.0],
+        vec![-4.0, 24.0, -41.0],
+        vec![1.0, 2.0, 3.0],
+        vec![0.0, -1.0, 5.0],
+    ])?;
+    for a in [tall.clone(), tall.transpose(), sample_matrix()?] {
+        let qr = a.qr();
+        let (q, r) = (qr.q(), qr.r());
+        let k = a.rows().min(a.cols());
+        assert_eq!(q.dimensions(), Dimensions::new(a.rows(), k), "Q should be reduced");
+        assert_close(&q.transpose().product(&q)?, &Matrix::identity(k), 1e-12, "Q should have orthonormal columns")?;
+        for i in 0..r.rows() {
+            for j in 0..i {
+                assert_eq!(r[i][j], 0.0, "R should be upper triangular");
+            }
+        }
+        assert_close(&q.product(&r)?, &a, 1e-10, "Q * R should be equal to A")?;
+    }
+    Ok(())
+}
+
+#[test]
+fn matrix_least_squares() -> MathResult<()> {
+    // y = 2 + 3x fitted on noisy points
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let noise = [0.1, -0.1, 0.05, -0.05, 0.0, 0.0];
+    let a = Matrix::new(xs.len(), 2, |i, j| if j == 0 { 1.0 } else { xs[i] });
+    let b = Matrix::new(xs.len(), 1, |i, _| 2.0 + 3.0 * xs[i] + noise[i]);
+    let x = least_squares(&a, &b)?;
+
+    // compare with normal equations solution
+    let at = a.transpose();
+    let expected = at.product(&a)?.solve(&at.product(&b)?)?;
+    assert_close(&x, &expected, 1e-10, "Least squares solution is incorrect")?;
+    assert!((x[0][0] - 2.0).abs() < 0.1 && (x[1][0] - 3.0).abs() < 0.05, "Unexpected coefficients {:?}", x);
+    Ok(())
+}
+
+#[test]
+fn matrix_least_squares_failures() -> MathResult<()> {
+    let deficient = Matrix::from_vector(&[
+        vec![1.0, 2.0],
+        vec![2.0, 4.0],
+        vec![3.0, 6.0],
+    ])?;
+    let b = Matrix::vector(&[1.0, 2.0, 3.0])?;
+    assert_eq!(deficient.qr().rank(), 1);
+    assert_eq!(
+        deficient.qr().solve_least_squares(&b),
+        Err(MathError::RankDeficientMatrix("least squares".to_string(), 1))
+    );
+
+    let wide = Matrix::zero(2, 3);
+    assert!(wide.qr().solve_least_squares(&Matrix::zero(2, 1)).is_err(), "Underdetermined systems aren't supported");
+    assert!(sample_matrix()?.qr().solve_least_squares(&b).is_err(), "Right side with wrong rows count should be rejected");
+    Ok(())
+}
+
+fn spd_matrix() -> MathResult<Matrix> {
+    Matrix::from_vector(&[
+        vec![4.0, 12.0, -16.0],
+        vec![12.0, 37.0, -43.0],
+        vec![-16.0, -43.0, 98.0],
+    ])
+}
+
+#[test]
+fn matrix_cholesky() -> MathResult<()> {
+    let a = spd_matrix()?;
+    let l = a.cholesky()?.l();
+    let expected = Matrix::from_vector(&[
+        vec![2.0, 0.0, 0.0],
+        vec![6.0, 1.0, 0.0],
+        vec![-8.0, 5.0, 3.0],
+    ])?;
+    assert_close(&l, &expected, 1e-12, "Cholesky factor is incorrect")?;
+    assert_close(&l.product(&l.transpose())?, &a, 1e-10, "L * L^T should be equal to A")?;
+    assert!((a.cholesky()?.determinant() - a.determinant()?).abs() < 1e-8);
+    Ok(())
+}
+
+#[test]
+fn matrix_cholesky_solve() -> MathResult<()> {
+    let a = spd_matrix()?;
+    let x = Matrix::from_vector(&[
+        vec![1.0, 0.5],
+        vec![-2.0, 1.5],
+        vec![3.0, -1.0],
+    ])?;
+    let b = a.product(&x)?;
+    assert_close(&a.cholesky_solve(&b)?, &x, 1e-9, "Cholesky solution is incorrect")?;
+    assert!(a.cholesky_solve(&Matrix::zero(2, 1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn matrix_cholesky_symmetry_tolerance() -> MathResult<()> {
+    // covariance computed in floating point is symmetric only approximately
+    let a = spd_matrix()?;
+    let mut perturbed = a.clone();
+    perturbed[0][1] += 1e-13;
+    assert!(!perturbed.is_symmetric(0.0), "Matrix should be slightly asymmetric");
+    assert!(perturbed.cholesky().is_ok(), "Round-off asymmetry should be tolerated");
+
+    let mut asymmetric = a.clone();
+    asymmetric[0][1] += 1.0;
+    assert_eq!(asymmetric.cholesky().err(), Some(MathError::NotSymmetricMatrix("cholesky".to_string())));
+    Ok(())
+}
+
+#[test]
+fn matrix_cholesky_failures() -> MathResult<()> {
+    let indefinite = Matrix::from_vector(&[
+        vec![1.0, 2.0],
+        vec![2.0, 1.0],
+    ])?;
+    assert_eq!(indefinite.cholesky().err(), Some(MathError::NotPositiveDefiniteMatrix("cholesky".to_string())));
+    assert!(matches!(Matrix::zero(2, 3).cholesky(), Err(MathError::NotSquareMatrix(_, _))));
+    Ok(())
+}
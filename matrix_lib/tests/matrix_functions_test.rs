@@ -35,19 +35,19 @@ fn matrix_eq() {
 
 #[test]
 fn matrix_function_add() -> MathResult<()> {
-    let m1 = Matrix::from_vector(&vec![
+    let m1 = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let m2 = Matrix::from_vector(&vec![
+    let m2 = Matrix::from_vector(&[
         vec![9.0, 8.0, 7.0],
         vec![6.0, 5.0, 4.0],
         vec![3.0, 2.0, 1.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![10.0, 10.0, 10.0],
         vec![10.0, 10.0, 10.0],
         vec![10.0, 10.0, 10.0]
@@ -60,19 +60,19 @@ fn matrix_function_add() -> MathResult<()> {
 
 #[test]
 fn matrix_function_sub() -> MathResult<()> {
-    let m1 = Matrix::from_vector(&vec![
+    let m1 = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let m2 = Matrix::from_vector(&vec![
+    let m2 = Matrix::from_vector(&[
         vec![9.0, 8.0, 7.0],
         vec![6.0, 5.0, 4.0],
         vec![3.0, 2.0, 1.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![-8.0, -6.0, -4.0],
         vec![-2.0,  0.0,  2.0],
         vec![ 4.0,  6.0,  8.0]
@@ -97,7 +97,7 @@ fn matrix_function_div() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_product() -> MathResult<()> {
-    let a = Matrix::from_vector(&vec![
+    let a = Matrix::from_vector(&[
         vec![1.0, 0.0, 1.0],
         vec![2.0, 1.0, 1.0],
         vec![0.0, 1.0, 1.0],
@@ -105,13 +105,13 @@ fn matrix_operation_product() -> MathResult<()> {
 
     ])?;
 
-    let b = Matrix::from_vector(&vec![
+    let b = Matrix::from_vector(&[
         vec![1.0, 2.0, 1.0],
         vec![2.0, 3.0, 1.0],
         vec![4.0, 2.0, 2.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![ 5.0, 4.0, 3.0],
         vec![ 8.0, 9.0, 5.0],
         vec![ 6.0, 5.0, 3.0],
@@ -122,19 +122,19 @@ fn matrix_operation_product() -> MathResult<()> {
     let ab = product(&a, &b)?;
     assert!(ab == expected, "Matrix product implemented incorrectly");
 
-    let a = Matrix::from_vector(&vec![
+    let a = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
 
     ])?;
 
-    let b = Matrix::from_vector(&vec![
+    let b = Matrix::from_vector(&[
         vec![ 7.0,  8.0],
         vec![ 9.0, 10.0],
         vec![11.0, 12.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![ 58.0,  64.0],
         vec![139.0, 154.0]
 
@@ -150,7 +150,7 @@ fn matrix_operation_product() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_product_failure() -> MathResult<()> {
-    let a = Matrix::from_vector(&vec![
+    let a = Matrix::from_vector(&[
         vec![1.0, 0.0, 1.0],
         vec![2.0, 1.0, 1.0],
         vec![0.0, 1.0, 1.0],
@@ -158,7 +158,7 @@ fn matrix_operation_product_failure() -> MathResult<()> {
 
     ])?;
 
-    let b = Matrix::from_vector(&vec![
+    let b = Matrix::from_vector(&[
         vec![1.0, 0.0, 1.0],
         vec![2.0, 1.0, 1.0],
         vec![0.0, 1.0, 1.0],
@@ -170,15 +170,30 @@ fn matrix_operation_product_failure() -> MathResult<()> {
     Ok(())
 }
 
+#[test]
+fn matrix_operation_product_large() -> MathResult<()> {
+    // dimensions are not multiples of the kernel block size
+    let a = Matrix::random_seeded(130, 70, 1);
+    let b = Matrix::random_seeded(70, 150, 2);
+    let expected = Matrix::new(130, 150, |i, j| (0..70).map(|k| a[i][k] * b[k][j]).sum());
+    let ab = product(&a, &b)?;
+    assert_eq!(ab.dimensions(), expected.dimensions());
+    assert!(ab.approx_eq(&expected, 1e-9), "Blocked product should match the naive one");
+
+    let empty: Matrix = product(&Matrix::zero(3, 0), &Matrix::zero(0, 4))?;
+    assert!(empty == Matrix::zero(3, 4), "Product with empty inner dimension should be zero");
+    Ok(())
+}
+
 #[test]
 fn matrix_operation_mul_scalar() -> MathResult<()> {
-    let m = Matrix::from_vector(&vec![
+    let m = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![ 2.0,  4.0,  6.0],
         vec![ 8.0, 10.0, 12.0],
         vec![14.0, 16.0, 18.0]
@@ -190,12 +205,12 @@ fn matrix_operation_mul_scalar() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_transpose() -> MathResult<()> {
-    let a = Matrix::from_vector(&vec![
+    let a = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![1.0, 4.0],
         vec![2.0, 5.0],
         vec![3.0, 6.0]
@@ -207,13 +222,13 @@ fn matrix_operation_transpose() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_power_integer() -> MathResult<()> {
-    let mut m = Matrix::from_vector(&vec![
+    let mut m = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![ 1.0,  4.0,  9.0],
         vec![16.0, 25.0, 36.0],
         vec![49.0, 64.0, 81.0]
@@ -225,19 +240,19 @@ fn matrix_operation_power_integer() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_add_assign() -> MathResult<()> {
-    let mut m1 = Matrix::from_vector(&vec![
+    let mut m1 = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let m2 = Matrix::from_vector(&vec![
+    let m2 = Matrix::from_vector(&[
         vec![9.0, 8.0, 7.0],
         vec![6.0, 5.0, 4.0],
         vec![3.0, 2.0, 1.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![10.0, 10.0, 10.0],
         vec![10.0, 10.0, 10.0],
         vec![10.0, 10.0, 10.0]
@@ -250,19 +265,19 @@ fn matrix_operation_add_assign() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_sub_assign() -> MathResult<()> {
-    let mut m1 = Matrix::from_vector(&vec![
+    let mut m1 = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let m2 = Matrix::from_vector(&vec![
+    let m2 = Matrix::from_vector(&[
         vec![9.0, 8.0, 7.0],
         vec![6.0, 5.0, 4.0],
         vec![3.0, 2.0, 1.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![-8.0, -6.0, -4.0],
         vec![-2.0,  0.0,  2.0],
         vec![ 4.0,  6.0,  8.0]
@@ -275,13 +290,13 @@ fn matrix_operation_sub_assign() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_scalar_multiplication() -> MathResult<()> {
-    let mut m = Matrix::from_vector(&vec![
+    let mut m = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![ 2.0,  4.0,  6.0],
         vec![ 8.0, 10.0, 12.0],
         vec![14.0, 16.0, 18.0]
@@ -293,13 +308,13 @@ fn matrix_operation_scalar_multiplication() -> MathResult<()> {
 
 #[test]
 fn matrix_operation_scalar_divide() -> MathResult<()> {
-    let expected = Matrix::from_vector(&vec![
+    let expected = Matrix::from_vector(&[
         vec![1.0, 2.0, 3.0],
         vec![4.0, 5.0, 6.0],
         vec![7.0, 8.0, 9.0]
     ])?;
 
-    let mut m = Matrix::from_vector(&vec![
+    let mut m = Matrix::from_vector(&[
         vec![ 2.0,  4.0,  6.0],
         vec![ 8.0, 10.0, 12.0],
         vec![14.0, 16.0, 18.0]
@@ -307,4 +322,84 @@ fn matrix_operation_scalar_divide() -> MathResult<()> {
     m.div_assign(2.0);
     assert!(m == expected, "Matrix divide by scalar & assign implemented incorrectly");
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn matrix_approx_eq_and_symmetry() -> MathResult<()> {
+    let a = Matrix::from_vector(&[
+        vec![1.0, 2.0],
+        vec![2.0 + 1e-9, 3.0]
+    ])?;
+    assert!(a != a.transpose(), "Exact comparison should fail");
+    assert!(a.approx_eq(&a.transpose(), 1e-8), "Approximate comparison should succeed");
+    assert!(!a.approx_eq(&a.transpose(), 1e-10));
+    assert!(a.is_symmetric(1e-8) && !a.is_symmetric(1e-10));
+    assert!(!Matrix::zero(2, 3).is_symmetric(1.0), "Non-square matrix can't be symmetric");
+    assert_eq!(a.max_abs(), 3.0);
+    Ok(())
+}
+
+#[test]
+fn matrix_sum_and_dot() -> MathResult<()> {
+    let a = Matrix::from_vector(&[
+        vec![1.0, 2.0, 3.0, 4.0, 5.0],
+        vec![6.0, 7.0, 8.0, 9.0, 10.0],
+    ])?;
+    let b = Matrix::new(2, 5, |_, _| 2.0);
+    assert_eq!(a.sum(), 55.0);
+    assert_eq!(a.mean(), 5.5);
+    assert_eq!(a.dot(&b)?, 110.0);
+    assert!(a.dot(&a.transpose()).is_err());
+    Ok(())
+}
+
+#[test]
+fn matrix_broadcast_functions() -> MathResult<()> {
+    let m = Matrix::from_vector(&[
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+    ])?;
+    let column = Matrix::vector(&[10.0, 20.0])?;
+    let row = Matrix::from_vector(&[vec![1.0, 2.0, 3.0]])?;
+
+    let expected = Matrix::from_vector(&[
+        vec![11.0, 12.0, 13.0],
+        vec![24.0, 25.0, 26.0],
+    ])?;
+    assert!(broadcast_add(&m, &column)? == expected, "Column broadcasting implemented incorrectly");
+    assert!(broadcast_sub(&expected, &column)? == m);
+
+    let expected = Matrix::from_vector(&[
+        vec![1.0,  4.0,  9.0],
+        vec![4.0, 10.0, 18.0],
+    ])?;
+    assert!(broadcast_mul(&row, &m)? == expected, "Row broadcasting implemented incorrectly");
+    assert!(broadcast_div(&expected, &row)? == m);
+
+    let outer = broadcast_mul(&column, &row)?;
+    assert!(outer == product(&column, &row)?, "Both operands should be broadcast");
+    assert!(broadcast_add(&m, &Matrix::from_scalar(1.0)?)? == m.map(|x| x + 1.0));
+    assert!(broadcast_add(&m, &m)? == add(&m, &m)?);
+    Ok(())
+}
+
+#[test]
+fn matrix_broadcast_incompatible() -> MathResult<()> {
+    let m = Matrix::random(2, 3);
+    assert!(matches!(broadcast_add(&m, &Matrix::random(3, 1)), Err(MathError::IncompatibleBroadcast(..))));
+    assert!(broadcast_mul(&m, &Matrix::random(1, 2)).is_err());
+    assert!(broadcast_sub(&m, &m.transpose_view()).is_err());
+    Ok(())
+}
+
+#[test]
+fn matrix_log_sum_exp() -> MathResult<()> {
+    let m = Matrix::from_vector(&[
+        vec![1.0, 1000.0],
+        vec![2.0, 1000.0],
+    ])?;
+    let expected = Matrix::from_vector(&[vec![(1f64.exp() + 2f64.exp()).ln(), 1000.0 + 2f64.ln()]])?;
+    assert!(log_sum_exp(&m).approx_eq(&expected, 1e-12), "Log-sum-exp should be stable for large inputs");
+    assert!(softmax(&m).approx_eq(&broadcast_sub(&m, &expected)?.map(f64::exp), 1e-12), "Softmax should be exp(x - lse)");
+    Ok(())
+}
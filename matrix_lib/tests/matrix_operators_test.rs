@@ -0,0 +1,86 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+    matrix_functions::*,
+};
+
+fn operands() -> MathResult<(Matrix, Matrix)> {
+    let a = Matrix::from_vector(&[
+        vec![1.0, 2.0],
+        vec![3.0, 4.0],
+    ])?;
+    let b = Matrix::from_vector(&[
+        vec![5.0, 6.0],
+        vec![7.0, 8.0],
+    ])?;
+    Ok((a, b))
+}
+
+#[test]
+fn matrix_operator_add_sub() -> MathResult<()> {
+    let (a, b) = operands()?;
+    let sum = add(&a, &b)?;
+    assert!(&a + &b == sum, "Borrowed add operator implemented incorrectly");
+    assert!(a.clone() + &b == sum && &a + b.clone() == sum && a.clone() + b.clone() == sum);
+
+    let difference = sub(&a, &b)?;
+    assert!(&a - &b == difference, "Borrowed sub operator implemented incorrectly");
+    assert!(a.clone() - &b == difference && &a - b.clone() == difference && a.clone() - b.clone() == difference);
+    Ok(())
+}
+
+#[test]
+fn matrix_operator_add_sub_views() -> MathResult<()> {
+    let (a, b) = operands()?;
+    let sum = add(&a, &b.transpose())?;
+    assert!(&a + b.transpose_view() == sum, "Add operator with view implemented incorrectly");
+    assert!(a.clone() + b.transpose_view() == sum && b.transpose_view() + &a == sum && b.transpose_view() + a.clone() == sum);
+    assert!(a.view() + b.transpose_view() == sum);
+
+    let difference = sub(&a, &b.transpose())?;
+    assert!(&a - b.transpose_view() == difference, "Sub operator with view implemented incorrectly");
+    assert!(a.clone() - b.transpose_view() == difference && a.view() - b.transpose_view() == difference);
+    assert!(b.transpose_view() - &a == -&difference && b.transpose_view() - a.clone() == -&difference);
+    assert!(-a.row_view(1)? == Matrix::from_vector(&[vec![-3.0, -4.0]])?);
+    Ok(())
+}
+
+#[test]
+fn matrix_operator_product() -> MathResult<()> {
+    let (a, b) = operands()?;
+    let expected = product(&a, &b)?;
+    assert!(&a * &b == expected, "Product operator implemented incorrectly");
+    assert!(a.clone() * b.clone() == expected && a.clone() * &b == expected && &a * b.clone() == expected);
+    assert!(a.transpose_view() * &b == product(&a.transpose(), &b)?);
+    assert!(&a * b.transpose_view() == product(&a, &b.transpose())?);
+    assert!(a.row_view(1)? * b.col_view(0)? == Matrix::from_scalar(43.0)?);
+    Ok(())
+}
+
+#[test]
+fn matrix_operator_scalar_and_neg() -> MathResult<()> {
+    let (a, _) = operands()?;
+    let doubled = a.mul(2.0);
+    assert!(&a * 2.0 == doubled && a.clone() * 2.0 == doubled, "Scalar multiplication operator implemented incorrectly");
+    assert!(2.0 * &a == doubled && 2.0 * a.clone() == doubled);
+    assert!(&doubled / 2.0 == a && doubled.clone() / 2.0 == a);
+    assert!(-&a == a.map(|x| -x) && -a.clone() + &a == Matrix::zero(2, 2));
+
+    let single: Matrix<f32> = a.cast();
+    assert!(0.5f32 * &single * 2.0 == single);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn matrix_operator_product_size_mismatch() {
+    let _ = Matrix::random(2, 3) * Matrix::random(2, 3);
+}
+
+#[test]
+#[should_panic]
+fn matrix_operator_add_size_mismatch() {
+    let _ = Matrix::random(2, 3) + Matrix::random(3, 2);
+}
@@ -0,0 +1,85 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+    matrix_random::*,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn std_deviation(m: &Matrix) -> f64 {
+    let mean = m.mean();
+    m.map(|x| (x - mean).powi(2)).mean().sqrt()
+}
+
+#[test]
+fn matrix_random_uniform() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let m = Matrix::random_uniform(50, 40, -3.0, 5.0, &mut rng);
+    for i in 0..m.rows() {
+        for j in 0..m.cols() {
+            assert!((-3.0..5.0).contains(&m[i][j]), "Value {} is out of range", m[i][j]);
+        }
+    }
+    assert!((m.mean() - 1.0).abs() < 0.2, "Uniform mean is {}", m.mean());
+}
+
+#[test]
+fn matrix_random_normal() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let m = Matrix::random_normal(100, 100, 3.0, 0.5, &mut rng);
+    assert!((m.mean() - 3.0).abs() < 0.02, "Normal mean is {}", m.mean());
+    let std = std_deviation(&m);
+    assert!((std - 0.5).abs() < 0.02, "Normal std is {}", std);
+}
+
+#[test]
+fn matrix_random_truncated_normal() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let m = Matrix::truncated_normal(100, 100, -1.0, 2.0, &mut rng);
+    for i in 0..m.rows() {
+        for j in 0..m.cols() {
+            assert!((m[i][j] + 1.0).abs() <= 4.0, "Value {} wasn't truncated", m[i][j]);
+        }
+    }
+    assert!((m.mean() + 1.0).abs() < 0.05, "Truncated normal mean is {}", m.mean());
+}
+
+#[test]
+fn matrix_random_bernoulli() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let m = Matrix::bernoulli(100, 100, 0.3, &mut rng);
+    for i in 0..m.rows() {
+        for j in 0..m.cols() {
+            assert!(m[i][j] == 0.0 || m[i][j] == 1.0, "Bernoulli value should be 0 or 1");
+        }
+    }
+    assert!((m.mean() - 0.3).abs() < 0.02, "Bernoulli mean is {}", m.mean());
+    assert_eq!(Matrix::bernoulli(3, 3, 0.0, &mut rng), Matrix::zero(3, 3));
+}
+
+#[test]
+fn matrix_random_permutation() -> MathResult<()> {
+    let mut rng = StdRng::seed_from_u64(5);
+    let p = Matrix::random_permutation(6, &mut rng);
+    let ones = Matrix::new(6, 1, |_, _| 1.0);
+    assert_eq!(p.product(&ones)?, ones, "Each row should contain a single one");
+    assert_eq!(p.transpose().product(&ones)?, ones, "Each column should contain a single one");
+    assert_eq!(p.product(&p.transpose())?, Matrix::identity(6), "Permutation matrix should be orthogonal");
+
+    let mut indices = permutation(10, &mut rng);
+    indices.sort();
+    assert_eq!(indices, (0..10).collect::<Vec<usize>>());
+    Ok(())
+}
+
+#[test]
+fn matrix_random_reproducible() {
+    let a = Matrix::random_normal(5, 5, 0.0, 1.0, &mut StdRng::seed_from_u64(7));
+    let b = Matrix::random_normal(5, 5, 0.0, 1.0, &mut StdRng::seed_from_u64(7));
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            assert_eq!(a[i][j].to_bits(), b[i][j].to_bits(), "Same rng seed should produce the same matrix");
+        }
+    }
+}
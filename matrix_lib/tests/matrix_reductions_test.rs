@@ -0,0 +1,64 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+    matrix_reductions::Axis,
+};
+
+fn sample() -> MathResult<Matrix> {
+    Matrix::from_vector(&[
+        vec![1.0, 5.0, 3.0],
+        vec![4.0, 2.0, 6.0],
+    ])
+}
+
+#[test]
+fn matrix_reduction_sum_mean() -> MathResult<()> {
+    let m = sample()?;
+    assert_eq!(m.sum_axis(Axis::Rows), Matrix::from_vector(&[vec![5.0, 7.0, 9.0]])?);
+    assert_eq!(m.sum_axis(Axis::Columns), Matrix::vector(&[9.0, 12.0])?);
+    assert_eq!(m.mean_axis(Axis::Rows)?, Matrix::from_vector(&[vec![2.5, 3.5, 4.5]])?);
+    assert_eq!(m.mean_axis(Axis::Columns)?, Matrix::vector(&[3.0, 4.0])?);
+    assert_eq!(m.sum_axis(Axis::Rows).sum(), m.sum());
+
+    let large = Matrix::random_seeded(37, 19, 5);
+    let expected = Matrix::new(1, 19, |_, j| (0..37).map(|i| large[i][j]).sum());
+    assert!(large.sum_axis(Axis::Rows).approx_eq(&expected, 1e-12));
+    Ok(())
+}
+
+#[test]
+fn matrix_reduction_extremums() -> MathResult<()> {
+    let m = sample()?;
+    assert_eq!(m.max_axis(Axis::Rows)?, Matrix::from_vector(&[vec![4.0, 5.0, 6.0]])?);
+    assert_eq!(m.max_axis(Axis::Columns)?, Matrix::vector(&[5.0, 6.0])?);
+    assert_eq!(m.min_axis(Axis::Rows)?, Matrix::from_vector(&[vec![1.0, 2.0, 3.0]])?);
+    assert_eq!(m.min_axis(Axis::Columns)?, Matrix::vector(&[1.0, 2.0])?);
+    assert_eq!(m.argmax_axis(Axis::Rows)?, vec![1, 0, 1]);
+    assert_eq!(m.argmax_axis(Axis::Columns)?, vec![1, 2]);
+    assert_eq!(Matrix::new(2, 3, |_, _| 1.0).argmax_axis(Axis::Columns)?, vec![0, 0], "Ties should resolve to the first position");
+
+    let empty: Matrix = Matrix::zero(0, 3);
+    assert!(matches!(empty.max_axis(Axis::Rows), Err(MathError::EmptyMatrix(_))));
+    assert!(empty.argmax_axis(Axis::Rows).is_err());
+    assert!(matches!(empty.mean_axis(Axis::Rows), Err(MathError::EmptyMatrix(_))));
+    assert!(matches!(empty.variance_axis(Axis::Rows), Err(MathError::EmptyMatrix(_))));
+    assert_eq!(empty.max_axis(Axis::Columns)?.dimensions(), Matrix::<f64>::zero(0, 1).dimensions());
+    Ok(())
+}
+
+#[test]
+fn matrix_reduction_variance_norm() -> MathResult<()> {
+    let m = sample()?;
+    assert_eq!(m.variance_axis(Axis::Rows)?, Matrix::from_vector(&[vec![2.25, 2.25, 2.25]])?);
+    let expected = Matrix::vector(&[8.0 / 3.0, 8.0 / 3.0])?;
+    assert!(m.variance_axis(Axis::Columns)?.approx_eq(&expected, 1e-12), "Variance implemented incorrectly");
+
+    assert!((m.norm() - 91f64.sqrt()).abs() < 1e-12);
+    let expected = Matrix::from_vector(&[vec![17f64.sqrt(), 29f64.sqrt(), 45f64.sqrt()]])?;
+    assert!(m.norm_axis(Axis::Rows).approx_eq(&expected, 1e-12));
+    let expected = Matrix::vector(&[35f64.sqrt(), 56f64.sqrt()])?;
+    assert!(m.norm_axis(Axis::Columns).approx_eq(&expected, 1e-12));
+    Ok(())
+}
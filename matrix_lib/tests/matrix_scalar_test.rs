@@ -0,0 +1,38 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+    matrix_functions::*,
+};
+
+#[test]
+fn matrix_single_precision_functions() -> MathResult<()> {
+    let a: Matrix<f32> = Matrix::from_vector(&[
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+    ])?;
+    let b: Matrix<f32> = Matrix::from_vector(&[
+        vec![ 7.0,  8.0],
+        vec![ 9.0, 10.0],
+        vec![11.0, 12.0],
+    ])?;
+    let expected = Matrix::from_vector(&[
+        vec![ 58.0f32,  64.0],
+        vec![139.0, 154.0],
+    ])?;
+    assert!(product(&a, &b)? == expected, "Single precision product implemented incorrectly");
+    assert!(add(&a, &a)? == a.mul(2.0), "Single precision add implemented incorrectly");
+    assert_eq!(a.sum(), 21.0f32);
+    assert_eq!(a.dot(&a)?, 91.0f32);
+    Ok(())
+}
+
+#[test]
+fn matrix_cast() {
+    let m = Matrix::random_seeded(9, 17, 3);
+    let single: Matrix<f32> = m.cast();
+    assert_eq!(single.dimensions(), m.dimensions());
+    assert!(single.cast::<f64>().approx_eq(&m, 1e-6), "Cast should keep values up to single precision");
+    assert!((single.sum() as f64 - m.sum()).abs() < 1e-4);
+}
@@ -0,0 +1,132 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+};
+
+fn assert_close(actual: &Matrix, expected: &Matrix, eps: f64, message: &str) {
+    assert!(actual.approx_eq(expected, eps), "{}: actual {:?}, expected {:?}", message, actual, expected);
+}
+
+fn symmetric_matrix() -> MathResult<Matrix> {
+    Matrix::from_vector(&[
+        vec![4.0, 1.0, -2.0, 2.0],
+        vec![1.0, 2.0, 0.0, 1.0],
+        vec![-2.0, 0.0, 3.0, -2.0],
+        vec![2.0, 1.0, -2.0, -1.0],
+    ])
+}
+
+fn tall_matrix() -> MathResult<Matrix> {
+    Matrix::from_vector(&[
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 10.0],
+        vec![-1.0, 0.5, 2.0],
+        vec![0.0, 1.0, -3.0],
+    ])
+}
+
+#[test]
+fn matrix_symmetric_eigen() -> MathResult<()> {
+    let a = symmetric_matrix()?;
+    let eigen = a.symmetric_eigen()?;
+    let values = eigen.eigenvalues();
+    let v = eigen.eigenvectors();
+    assert!(values.windows(2).all(|w| w[0] >= w[1]), "Eigenvalues should be sorted: {:?}", values);
+    assert_close(&v.transpose().product(v)?, &Matrix::identity(4), 1e-12, "Eigenvectors should be orthonormal");
+    let reconstructed = v.product(&Matrix::diagonal(values))?.product(&v.transpose())?;
+    assert_close(&reconstructed, &a, 1e-10, "V * L * V^T should be equal to A");
+    let trace: f64 = (0..4).map(|i| a[i][i]).sum();
+    assert!((values.iter().sum::<f64>() - trace).abs() < 1e-10, "Sum of eigenvalues should be equal to trace");
+    Ok(())
+}
+
+#[test]
+fn matrix_symmetric_eigen_known_values() -> MathResult<()> {
+    let a = Matrix::from_vector(&[
+        vec![2.0, 1.0],
+        vec![1.0, 2.0],
+    ])?;
+    let eigen = a.symmetric_eigen()?;
+    assert!((eigen.eigenvalues()[0] - 3.0).abs() < 1e-12);
+    assert!((eigen.eigenvalues()[1] - 1.0).abs() < 1e-12);
+
+    let not_symmetric = Matrix::from_vector(&[
+        vec![1.0, 2.0],
+        vec![0.0, 1.0],
+    ])?;
+    assert!(matches!(not_symmetric.symmetric_eigen(), Err(MathError::NotSymmetricMatrix(_))));
+    Ok(())
+}
+
+#[test]
+fn matrix_svd_reconstruction() -> MathResult<()> {
+    for a in [tall_matrix()?, tall_matrix()?.transpose(), symmetric_matrix()?] {
+        let svd = a.svd()?;
+        let k = a.rows().min(a.cols());
+        assert_eq!(svd.singular_values().len(), k);
+        assert!(svd.singular_values().windows(2).all(|w| w[0] >= w[1]), "Singular values should be sorted");
+        assert!(svd.singular_values().iter().all(|s| *s >= 0.0), "Singular values should be non-negative");
+        assert_close(&svd.u().transpose().product(svd.u())?, &Matrix::identity(k), 1e-12, "U should have orthonormal columns");
+        assert_close(&svd.vt().product(&svd.vt().transpose())?, &Matrix::identity(k), 1e-12, "V^T should have orthonormal rows");
+        let reconstructed = svd.u().product(&svd.sigma())?.product(svd.vt())?;
+        assert_close(&reconstructed, &a, 1e-10, "U * S * V^T should be equal to A");
+    }
+    Ok(())
+}
+
+#[test]
+fn matrix_svd_rank_deficient() -> MathResult<()> {
+    let column = Matrix::vector(&[1.0, 2.0, -1.0, 0.5])?;
+    let row = Matrix::from_vector(&[vec![3.0, -1.0, 2.0]])?;
+    let outer = column.product(&row)?;
+    for a in [outer.clone(), outer.transpose(), Matrix::zero(3, 2), Matrix::zero(2, 3)] {
+        let svd = a.svd()?;
+        let k = a.rows().min(a.cols());
+        assert_close(&svd.u().transpose().product(svd.u())?, &Matrix::identity(k), 1e-12, "U should have orthonormal columns");
+        assert_close(&svd.vt().product(&svd.vt().transpose())?, &Matrix::identity(k), 1e-12, "V^T should have orthonormal rows");
+        let reconstructed = svd.u().product(&svd.sigma())?.product(svd.vt())?;
+        assert_close(&reconstructed, &a, 1e-10, "U * S * V^T should be equal to A");
+    }
+    assert_eq!(outer.rank()?, 1);
+    Ok(())
+}
+
+#[test]
+fn matrix_rank_and_condition_number() -> MathResult<()> {
+    let deficient = Matrix::from_vector(&[
+        vec![1.0, 2.0, 3.0],
+        vec![2.0, 4.0, 6.0],
+        vec![1.0, 0.0, 1.0],
+    ])?;
+    assert_eq!(deficient.rank()?, 2);
+    assert_eq!(tall_matrix()?.rank()?, 3);
+    assert_eq!(deficient.condition_number()?, f64::INFINITY);
+
+    let diagonal = Matrix::diagonal(&[10.0, -2.0, 0.5]);
+    assert!((diagonal.condition_number()? - 20.0).abs() < 1e-12);
+    assert!((diagonal.spectral_norm()? - 10.0).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn matrix_pseudo_inverse() -> MathResult<()> {
+    let a = tall_matrix()?;
+    let pinv = a.pseudo_inverse()?;
+    assert_eq!(pinv.dimensions(), a.transpose().dimensions());
+    assert_close(&a.product(&pinv)?.product(&a)?, &a, 1e-10, "A * A+ * A should be equal to A");
+    assert_close(&pinv.product(&a)?, &Matrix::identity(3), 1e-10, "A+ is a left inverse for full column rank");
+
+    let square = symmetric_matrix()?;
+    assert_close(&square.pseudo_inverse()?, &square.inverse()?, 1e-10, "Pseudo-inverse of invertible matrix is its inverse");
+
+    let deficient = Matrix::from_vector(&[
+        vec![1.0, 2.0],
+        vec![2.0, 4.0],
+    ])?;
+    let pinv = deficient.pseudo_inverse()?;
+    assert_close(&pinv.product(&deficient)?.product(&pinv)?, &pinv, 1e-10, "A+ * A * A+ should be equal to A+");
+    Ok(())
+}
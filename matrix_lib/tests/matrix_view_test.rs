@@ -0,0 +1,94 @@
+extern crate matrix_lib;
+
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+    matrix_functions::*,
+};
+
+fn sample() -> MathResult<Matrix> {
+    Matrix::from_vector(&[
+        vec![1.0,  2.0,  3.0,  4.0],
+        vec![5.0,  6.0,  7.0,  8.0],
+        vec![9.0, 10.0, 11.0, 12.0],
+    ])
+}
+
+#[test]
+fn matrix_view_block() -> MathResult<()> {
+    let m = sample()?;
+    let block = m.block(1, 1, 2, 2)?;
+    let expected = Matrix::from_vector(&[
+        vec![ 6.0,  7.0],
+        vec![10.0, 11.0],
+    ])?;
+    assert!(block.to_matrix() == expected, "Block view implemented incorrectly");
+    assert!(block.as_slice().is_none(), "Block of a wider matrix isn't contiguous");
+    assert_eq!(block.block(1, 0, 1, 2)?.to_matrix(), Matrix::from_vector(&[vec![10.0, 11.0]])?);
+    assert_eq!(m.block(1, 0, 2, 4)?.as_slice(), Some(&m.as_slice()[4..]));
+    assert!(m.block(2, 2, 2, 2).is_err());
+    assert!(m.block(0, 5, 1, 0).is_err());
+    assert_eq!(m.block(3, 4, 0, 0)?.rows(), 0);
+    assert!(block.get(2, 0).is_err());
+    Ok(())
+}
+
+#[test]
+fn matrix_view_rows_columns_transpose() -> MathResult<()> {
+    let m = sample()?;
+    assert_eq!(m.row_view(1)?.to_matrix(), Matrix::from_vector(&[vec![5.0, 6.0, 7.0, 8.0]])?);
+    assert_eq!(m.col_view(2)?.to_matrix(), Matrix::vector(&[3.0, 7.0, 11.0])?);
+    assert!(m.row_view(3).is_err() && m.col_view(4).is_err());
+
+    let transposed = m.transpose_view();
+    assert_eq!(transposed.dimensions(), m.transpose().dimensions());
+    assert!(transposed.to_matrix() == m.transpose(), "Transposed view implemented incorrectly");
+    assert_eq!(transposed.get(3, 1)?, 8.0);
+    assert!(transposed.transpose().to_matrix() == m);
+    assert_eq!(transposed.row_view(0)?.to_matrix(), Matrix::from_vector(&[vec![1.0, 5.0, 9.0]])?);
+    Ok(())
+}
+
+#[test]
+fn matrix_view_functions() -> MathResult<()> {
+    let m = sample()?;
+    let a = m.block(0, 0, 3, 2)?;
+    let b = m.block(0, 2, 3, 2)?;
+    assert!(add(&a, &b)? == add(&a.to_matrix(), &b.to_matrix())?, "Views sum implemented incorrectly");
+    assert!(sub(&b, &a)? == Matrix::new(3, 2, |_, _| 2.0));
+    assert!(add(&m.transpose_view(), &m.transpose())? == m.transpose().mul(2.0));
+    assert!(add(&a, &m).is_err());
+
+    let gram = product(&m.transpose_view(), &m)?;
+    assert!(gram == product(&m.transpose(), &m)?, "Product with transposed left view implemented incorrectly");
+    let outer = product(&m, &m.transpose_view())?;
+    assert!(outer == product(&m, &m.transpose())?, "Product with transposed right view implemented incorrectly");
+    let part = product(&m.block(1, 1, 2, 3)?, &m.block(0, 1, 3, 2)?)?;
+    assert!(part == product(&m.block(1, 1, 2, 3)?.to_matrix(), &m.block(0, 1, 3, 2)?.to_matrix())?);
+    assert!(m.product(&m.row_view(0)?).is_err());
+    Ok(())
+}
+
+#[test]
+fn matrix_view_mut() -> MathResult<()> {
+    let mut m = sample()?;
+    {
+        let mut block = m.block_mut(1, 2, 2, 2)?;
+        block.fill(0.0);
+        block.set(0, 0, -1.0)?;
+        assert!(block.set(2, 0, 1.0).is_err());
+    }
+    m.view_mut().col_view_mut(0)?.modify(|x| x * 10.0);
+    let expected = Matrix::from_vector(&[
+        vec![10.0,  2.0,  3.0, 4.0],
+        vec![50.0,  6.0, -1.0, 0.0],
+        vec![90.0, 10.0,  0.0, 0.0],
+    ])?;
+    assert!(m == expected, "Mutable views modified incorrectly");
+
+    let source = Matrix::vector(&[1.0, 2.0, 3.0, 4.0])?;
+    m.view_mut().transpose().col_view_mut(2)?.assign(&source)?;
+    assert_eq!(m.row_view(2)?.to_matrix(), source.transpose());
+    assert!(m.block_mut(0, 0, 2, 2)?.assign(&source).is_err());
+    Ok(())
+}
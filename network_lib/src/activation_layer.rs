@@ -1,56 +1,171 @@
 use matrix_lib::{
-    errors::MathResult, 
+    errors::MathResult,
     matrix::*,
     matrix_functions::*,
+    scalar::Scalar,
 };
-use super::layer::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use super::{
+    layer::*,
+    serialization::LayerConfig,
+};
+
+const SELU_LAMBDA: f64 = 1.050_700_987_355_480_5;
+const SELU_ALPHA: f64 = 1.673_263_242_354_377_2;
+/// sqrt(2 / pi) used by tanh approximation of GELU
+const GELU_SCALE: f64 = 0.797_884_560_802_865_4;
+const GELU_CUBIC: f64 = 0.044_715;
 
-pub struct Activation {
-    activation: fn(f64) -> f64,
-    activation_prime: fn(f64) -> f64,
-    input: Matrix
+/// Element-wise activation function
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ActivationFunction {
+    Linear,
+    Tanh,
+    Sigmoid,
+    Relu,
+    /// slope for negative inputs
+    LeakyRelu(f64),
+    /// alpha, the saturation value for negative inputs
+    Elu(f64),
+    Selu,
+    /// tanh approximation
+    Gelu,
+    Swish,
+    Softplus,
 }
 
-impl Activation {
-    pub fn tanh() -> Self {
-        Self {
-            activation: f64::tanh,
-            activation_prime: |x| 1.0 - x.tanh().powi(2),
-            input: Matrix::empty()
+impl ActivationFunction {
+    pub fn value<T: Scalar>(&self, x: T) -> T {
+        let c = T::from_f64;
+        match *self {
+            Self::Linear => x,
+            Self::Tanh => x.tanh(),
+            Self::Sigmoid => sigmoid(x),
+            Self::Relu => x.max(T::ZERO),
+            Self::LeakyRelu(slope) => if x > T::ZERO { x } else { c(slope) * x },
+            Self::Elu(alpha) => if x > T::ZERO { x } else { c(alpha) * x.exp_m1() },
+            Self::Selu => c(SELU_LAMBDA) * if x > T::ZERO { x } else { c(SELU_ALPHA) * x.exp_m1() },
+            Self::Gelu => c(0.5) * x * (T::ONE + (c(GELU_SCALE) * (x + c(GELU_CUBIC) * x.powi(3))).tanh()),
+            Self::Swish => x * sigmoid(x),
+            Self::Softplus => x.max(T::ZERO) + (-x.abs()).exp().ln_1p(),
         }
     }
 
-    pub fn sigmoid() -> Self {
-        Self {
-            activation: self::sigmoid,
-            activation_prime: |x| {
+    /// derivative at `x`, where `y` is the already computed value at `x`
+    pub fn derivative<T: Scalar>(&self, x: T, y: T) -> T {
+        let c = T::from_f64;
+        match *self {
+            Self::Linear => T::ONE,
+            Self::Tanh => T::ONE - y * y,
+            Self::Sigmoid => y * (T::ONE - y),
+            Self::Relu => if x > T::ZERO { T::ONE } else { T::ZERO },
+            Self::LeakyRelu(slope) => if x > T::ZERO { T::ONE } else { c(slope) },
+            Self::Elu(alpha) => if x > T::ZERO { T::ONE } else { y + c(alpha) },
+            Self::Selu => if x > T::ZERO { c(SELU_LAMBDA) } else { y + c(SELU_LAMBDA * SELU_ALPHA) },
+            Self::Gelu => {
+                let t = (c(GELU_SCALE) * (x + c(GELU_CUBIC) * x.powi(3))).tanh();
+                c(0.5) * (T::ONE + t) + c(0.5) * x * (T::ONE - t * t) * c(GELU_SCALE) * (T::ONE + c(3.0 * GELU_CUBIC) * x * x)
+            },
+            Self::Swish => {
                 let s = sigmoid(x);
-                s * (1.0 - s)
+                y + s * (T::ONE - y)
             },
+            Self::Softplus => sigmoid(x),
+        }
+    }
+}
+
+/// Applies the function element-wise
+pub struct Activation<T: Scalar = f64> {
+    function: ActivationFunction,
+    input: Matrix<T>,
+    output: Matrix<T>,
+}
+
+impl<T: Scalar> Activation<T> {
+    pub fn new(function: ActivationFunction) -> Self {
+        Self {
+            function,
             input: Matrix::empty(),
+            output: Matrix::empty(),
         }
     }
+
+    pub fn linear() -> Self {
+        Self::new(ActivationFunction::Linear)
+    }
+
+    pub fn tanh() -> Self {
+        Self::new(ActivationFunction::Tanh)
+    }
+
+    pub fn sigmoid() -> Self {
+        Self::new(ActivationFunction::Sigmoid)
+    }
+
+    pub fn relu() -> Self {
+        Self::new(ActivationFunction::Relu)
+    }
+
+    pub fn leaky_relu(slope: f64) -> Self {
+        Self::new(ActivationFunction::LeakyRelu(slope))
+    }
+
+    pub fn elu(alpha: f64) -> Self {
+        Self::new(ActivationFunction::Elu(alpha))
+    }
+
+    pub fn selu() -> Self {
+        Self::new(ActivationFunction::Selu)
+    }
+
+    pub fn gelu() -> Self {
+        Self::new(ActivationFunction::Gelu)
+    }
+
+    pub fn swish() -> Self {
+        Self::new(ActivationFunction::Swish)
+    }
+
+    pub fn softplus() -> Self {
+        Self::new(ActivationFunction::Softplus)
+    }
+
+    pub fn function(&self) -> ActivationFunction {
+        self.function
+    }
 }
 
-fn sigmoid(x: f64) -> f64 {
-    1.0 / (1.0 + f64::exp(-x))
+fn sigmoid<T: Scalar>(x: T) -> T {
+    T::ONE / (T::ONE + (-x).exp())
 }
 
-impl Layer for Activation {
-    fn eval(&self, input: &Matrix) -> MathResult<Matrix> {
+impl<T: Scalar> Layer<T> for Activation<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let function = self.function;
         Ok(
-            input.map(self.activation)
+            input.map(|x| function.value(x))
         )
     }
 
-    fn forward(&mut self, input: Matrix) -> MathResult<Matrix> {
-        let result = self.eval(&input);
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        self.output = self.eval(&input)?;
         self.input = input;
-        result
+        Ok(self.output.clone())
     }
 
-    fn backward(&mut self, output_gradient: &Matrix, _learning_rate: f64) -> MathResult<Matrix> {
-        let matrix = self.input.map(self.activation_prime);
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let function = self.function;
+        let matrix = map(&self.input, &self.output, |x, y| function.derivative(x, y))?;
         output_gradient.mul(&matrix)
     }
-}
\ No newline at end of file
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::Activation {
+            function: self.function,
+        }
+    }
+}
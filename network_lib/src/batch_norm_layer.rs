@@ -0,0 +1,168 @@
+use matrix_lib::{
+    errors::*,
+    matrix::*,
+    matrix_functions::*,
+    matrix_reductions::Axis,
+    scalar::Scalar,
+};
+use super::{
+    layer::*,
+    serialization::LayerConfig,
+};
+
+const DEFAULT_MOMENTUM: f64 = 0.9;
+const DEFAULT_EPSILON: f64 = 1e-5;
+
+/// Batch normalization of every feature (row) over the batch (columns) followed by learnable scale `gamma` and shift `beta`.
+/// Training mode normalizes with the batch statistics and accumulates their moving averages,
+/// which are used by `eval` and inference mode instead
+pub struct BatchNorm<T: Scalar = f64> {
+    gamma: Matrix<T>,
+    beta: Matrix<T>,
+    gamma_gradient: Matrix<T>,
+    beta_gradient: Matrix<T>,
+    running_mean: Matrix<T>,
+    running_variance: Matrix<T>,
+    momentum: f64,
+    epsilon: f64,
+    mode: Mode,
+    /// normalized input and `1 / sqrt(variance + epsilon)` from the last forward pass
+    normalized: Matrix<T>,
+    inv_std: Matrix<T>,
+    /// whether the last forward pass used batch statistics
+    batch_statistics: bool,
+}
+
+impl<T: Scalar> BatchNorm<T> {
+    pub fn new(size: usize) -> Self {
+        Self::configured(size, DEFAULT_MOMENTUM, DEFAULT_EPSILON).expect("default parameters are valid")
+    }
+
+    /// running statistics are updated as `momentum * running + (1 - momentum) * batch`,
+    /// `epsilon` is added to the variance. Fails unless `momentum` is in [0, 1] and `epsilon` is positive
+    pub fn configured(size: usize, momentum: f64, epsilon: f64) -> MathResult<Self> {
+        if !(0.0..=1.0).contains(&momentum) {
+            return Err(MathError::InvalidParameter("batch norm momentum".to_string(), momentum));
+        }
+        if !(epsilon > 0.0 && epsilon.is_finite()) {
+            return Err(MathError::InvalidParameter("batch norm epsilon".to_string(), epsilon));
+        }
+        Ok(Self {
+            gamma: Matrix::new(size, 1, |_, _| T::ONE),
+            beta: Matrix::zero(size, 1),
+            gamma_gradient: Matrix::zero(size, 1),
+            beta_gradient: Matrix::zero(size, 1),
+            running_mean: Matrix::zero(size, 1),
+            running_variance: Matrix::new(size, 1, |_, _| T::ONE),
+            momentum,
+            epsilon,
+            mode: Mode::Training,
+            normalized: Matrix::empty(),
+            inv_std: Matrix::empty(),
+            batch_statistics: false,
+        })
+    }
+
+    pub fn running_mean(&self) -> &Matrix<T> {
+        &self.running_mean
+    }
+
+    pub fn running_variance(&self) -> &Matrix<T> {
+        &self.running_variance
+    }
+
+    fn check_input(&self, input: &Matrix<T>) -> MathResult<()> {
+        if input.rows() == self.gamma.rows() {
+            Ok(())
+        } else {
+            Err(MathError::IncorrectMatricesDimensions("batch norm".to_string(), self.gamma.dimensions(), input.dimensions()))
+        }
+    }
+
+    fn inv_std(&self, variance: &Matrix<T>) -> Matrix<T> {
+        let epsilon = T::from_f64(self.epsilon);
+        variance.map(|v| T::ONE / (v + epsilon).sqrt())
+    }
+
+    /// returns normalized input and its scaled and shifted version
+    fn normalize(&self, input: &Matrix<T>, mean: &Matrix<T>, inv_std: &Matrix<T>) -> MathResult<(Matrix<T>, Matrix<T>)> {
+        let normalized = broadcast_mul(&broadcast_sub(input, mean)?, inv_std)?;
+        let output = broadcast_add(&broadcast_mul(&normalized, &self.gamma)?, &self.beta)?;
+        Ok((normalized, output))
+    }
+
+    fn update_running(running: &mut Matrix<T>, batch: &Matrix<T>, momentum: T) {
+        running.modify_other(batch, |r, b| momentum * r + (T::ONE - momentum) * b)
+            .expect("running statistics have the layer size");
+    }
+}
+
+impl<T: Scalar> Layer<T> for BatchNorm<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        self.check_input(input)?;
+        let (_, output) = self.normalize(input, &self.running_mean, &self.inv_std(&self.running_variance))?;
+        Ok(output)
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        self.check_input(&input)?;
+        self.batch_statistics = self.mode == Mode::Training && input.cols() > 0;
+        let (mean, variance) = if self.batch_statistics {
+            let (mean, variance) = (input.mean_axis(Axis::Columns)?, input.variance_axis(Axis::Columns)?);
+            let momentum = T::from_f64(self.momentum);
+            Self::update_running(&mut self.running_mean, &mean, momentum);
+            Self::update_running(&mut self.running_variance, &variance, momentum);
+            (mean, variance)
+        } else {
+            (self.running_mean.clone(), self.running_variance.clone())
+        };
+        self.inv_std = self.inv_std(&variance);
+        let (normalized, output) = self.normalize(&input, &mean, &self.inv_std)?;
+        self.normalized = normalized;
+        Ok(output)
+    }
+
+    /// with batch statistics the input gradient is `gamma * inv_std / N * (N * dy - sum(dy) - x_hat * sum(dy * x_hat))`
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        if !self.normalized.is_same_size(output_gradient) {
+            return Err(MathError::IncorrectMatricesDimensions("batch norm backward".to_string(), self.normalized.dimensions(), output_gradient.dimensions()));
+        }
+        self.beta_gradient = output_gradient.sum_axis(Axis::Columns);
+        self.gamma_gradient = mul(output_gradient, &self.normalized)?.sum_axis(Axis::Columns);
+        let scale = mul(&self.gamma, &self.inv_std)?;
+        if !self.batch_statistics {
+            return broadcast_mul(output_gradient, &scale);
+        }
+        let batch = T::from_f64(output_gradient.cols() as f64);
+        let centered = broadcast_sub(&(output_gradient * batch), &self.beta_gradient)?;
+        let projection = broadcast_mul(&self.normalized, &self.gamma_gradient)?;
+        broadcast_mul(&(centered - &projection), &(scale / batch))
+    }
+
+    fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter { value: &mut self.gamma, gradient: &self.gamma_gradient },
+            Parameter { value: &mut self.beta, gradient: &self.beta_gradient },
+        ]
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::BatchNorm {
+            size: self.gamma.rows(),
+            momentum: self.momentum,
+            epsilon: self.epsilon,
+        }
+    }
+
+    fn state(&self) -> Vec<&Matrix<T>> {
+        vec![&self.gamma, &self.beta, &self.running_mean, &self.running_variance]
+    }
+
+    fn state_mut(&mut self) -> Vec<&mut Matrix<T>> {
+        vec![&mut self.gamma, &mut self.beta, &mut self.running_mean, &mut self.running_variance]
+    }
+}
@@ -0,0 +1,126 @@
+use matrix_lib::{
+    dimensions::Dimensions,
+    errors::*,
+    matrix::*,
+    matrix_functions::*,
+    matrix_reductions::Axis,
+    scalar::Scalar,
+};
+use super::{
+    image::*,
+    initializer::Initializer,
+    layer::*,
+    serialization::LayerConfig,
+};
+
+/// 2D convolution (cross-correlation) of column-stacked images, see `ImageShape` for the layout.
+/// Windows are unfolded with im2col, so both passes are single matrix products
+pub struct Conv2D<T: Scalar = f64> {
+    input_shape: ImageShape,
+    output_shape: ImageShape,
+    window: Window,
+    /// a row per output channel and a column per input channel and kernel element
+    weight: Matrix<T>,
+    bias: Matrix<T>,
+    weight_gradient: Matrix<T>,
+    bias_gradient: Matrix<T>,
+    /// unfolded input of the last forward pass
+    columns: Matrix<T>,
+    batch: usize,
+}
+
+impl<T: Scalar> Conv2D<T> {
+    /// Xavier uniform weights with random seed and zero bias
+    pub fn new(input_shape: ImageShape, output_channels: usize, window: Window) -> MathResult<Self> {
+        Self::initialized(input_shape, output_channels, window, Initializer::XavierUniform, rand::random())
+    }
+
+    pub fn initialized(input_shape: ImageShape, output_channels: usize, window: Window, initializer: Initializer, seed: u64) -> MathResult<Self> {
+        let output = windowed_shape("conv2d", input_shape, &window)?;
+        let kernel_size = input_shape.channels * window.kernel.0 * window.kernel.1;
+        Ok(Self {
+            input_shape,
+            output_shape: ImageShape::new(output_channels, output.height, output.width),
+            window,
+            weight: initializer.seeded_matrix(output_channels, kernel_size, seed).cast(),
+            bias: Matrix::zero(output_channels, 1),
+            weight_gradient: Matrix::zero(output_channels, kernel_size),
+            bias_gradient: Matrix::zero(output_channels, 1),
+            columns: Matrix::empty(),
+            batch: 0,
+        })
+    }
+
+    pub fn input_shape(&self) -> ImageShape {
+        self.input_shape
+    }
+
+    pub fn output_shape(&self) -> ImageShape {
+        self.output_shape
+    }
+
+    fn check_input(&self, input: &Matrix<T>) -> MathResult<()> {
+        if input.rows() == self.input_shape.size() {
+            Ok(())
+        } else {
+            Err(MathError::IncorrectMatricesDimensions("conv2d".to_string(), Dimensions::new(self.input_shape.size(), input.cols()), input.dimensions()))
+        }
+    }
+
+    /// output channels by positions and images, stacked back into output images
+    fn convolve(&self, columns: &Matrix<T>, batch: usize) -> MathResult<Matrix<T>> {
+        let mut output = product(&self.weight, columns)?;
+        add_bias(&mut output, &self.bias);
+        output.reshape(self.output_shape.size(), batch)
+    }
+}
+
+impl<T: Scalar> Layer<T> for Conv2D<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        self.check_input(input)?;
+        self.convolve(&im2col(input, self.input_shape, &self.window), input.cols())
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        self.check_input(&input)?;
+        self.batch = input.cols();
+        self.columns = im2col(&input, self.input_shape, &self.window);
+        self.convolve(&self.columns, self.batch)
+    }
+
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let expected = Dimensions::new(self.output_shape.size(), self.batch);
+        if output_gradient.dimensions() != expected {
+            return Err(MathError::IncorrectMatricesDimensions("conv2d backward".to_string(), expected, output_gradient.dimensions()));
+        }
+        let positions = self.output_shape.height * self.output_shape.width;
+        let gradient = output_gradient.clone().reshape(self.output_shape.channels, positions * self.batch)?;
+        self.weight_gradient = &gradient * self.columns.transpose_view();
+        self.bias_gradient = gradient.sum_axis(Axis::Columns);
+        let columns_gradient = self.weight.transpose_view() * &gradient;
+        Ok(col2im(&columns_gradient, self.input_shape, &self.window, self.batch))
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter { value: &mut self.weight, gradient: &self.weight_gradient },
+            Parameter { value: &mut self.bias, gradient: &self.bias_gradient },
+        ]
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::Conv2D {
+            input_shape: self.input_shape,
+            output_channels: self.output_shape.channels,
+            window: self.window,
+        }
+    }
+
+    fn state(&self) -> Vec<&Matrix<T>> {
+        vec![&self.weight, &self.bias]
+    }
+
+    fn state_mut(&mut self) -> Vec<&mut Matrix<T>> {
+        vec![&mut self.weight, &mut self.bias]
+    }
+}
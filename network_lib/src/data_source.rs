@@ -1,24 +1,27 @@
 use matrix_lib::{
-    matrix::Matrix
+    matrix::Matrix,
+    errors::*,
+    scalar::Scalar,
 };
 
-pub struct TrainItem {
-    pub input: Matrix,
-    pub output: Matrix,
+pub struct TrainItem<T: Scalar = f64> {
+    pub input: Matrix<T>,
+    pub output: Matrix<T>,
 }
 
-pub struct TrainDataSource {
-    data: Vec<TrainItem>
+#[derive(Default)]
+pub struct TrainDataSource<T: Scalar = f64> {
+    data: Vec<TrainItem<T>>
 }
 
-impl TrainDataSource {
+impl<T: Scalar> TrainDataSource<T> {
     pub fn new() -> Self {
         Self {
             data: Vec::new(),
         }
     }
 
-    pub fn push(&mut self, input: Matrix, output: Matrix) {
+    pub fn push(&mut self, input: Matrix<T>, output: Matrix<T>) {
         let item = TrainItem {
             input,
             output
@@ -26,7 +29,47 @@ impl TrainDataSource {
         self.data.push(item);
     }
 
-    pub fn content(&self) -> &Vec<TrainItem> {
+    pub fn content(&self) -> &Vec<TrainItem<T>> {
         &self.data
     }
+
+    /// packs consecutive items into column-stacked matrices (features x batch_size),
+    /// the last batch may be smaller than batch_size, which must be positive
+    pub fn batches(&self, batch_size: usize) -> MathResult<Vec<TrainItem<T>>> {
+        if batch_size == 0 {
+            return Err(MathError::InvalidParameter("batch size".to_string(), 0.0));
+        }
+        self.data
+            .chunks(batch_size)
+            .map(|chunk| {
+                let inputs: Vec<&Matrix<T>> = chunk.iter().map(|item| &item.input).collect();
+                let outputs: Vec<&Matrix<T>> = chunk.iter().map(|item| &item.output).collect();
+                Ok(TrainItem {
+                    input: stack_columns(&inputs)?,
+                    output: stack_columns(&outputs)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// stacks matrices with the same rows number side by side
+fn stack_columns<T: Scalar>(columns: &[&Matrix<T>]) -> MathResult<Matrix<T>> {
+    let Some(first) = columns.first() else {
+        return Ok(Matrix::empty());
+    };
+    let rows = first.rows();
+    if let Some(item) = columns.iter().find(|m| m.rows() != rows) {
+        return Err(MathError::IncorrectMatricesDimensions("column stack".to_string(), first.dimensions(), item.dimensions()));
+    }
+    let cols = columns.iter().map(|m| m.cols()).sum();
+    let mut matrix = Matrix::zero(rows, cols);
+    let mut offset = 0;
+    for item in columns {
+        for i in 0..rows {
+            matrix[i][offset..offset + item.cols()].copy_from_slice(&item[i]);
+        }
+        offset += item.cols();
+    }
+    Ok(matrix)
 }
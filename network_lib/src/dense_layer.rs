@@ -1,44 +1,89 @@
 use matrix_lib::{
-    errors::MathResult, 
+    dimensions::Dimensions,
+    errors::*,
     matrix::*,
     matrix_functions::*,
+    matrix_reductions::Axis,
+    scalar::Scalar,
+};
+use super::{
+    initializer::Initializer,
+    layer::*,
+    serialization::LayerConfig,
 };
-use super::layer::*;
 
-pub struct Dense {
-    weight: Matrix,
-    bias: Matrix,
-    input: Matrix
+pub struct Dense<T: Scalar = f64> {
+    weight: Matrix<T>,
+    bias: Matrix<T>,
+    weight_gradient: Matrix<T>,
+    bias_gradient: Matrix<T>,
+    input: Matrix<T>
 }
 
-impl Dense {
+impl<T: Scalar> Dense<T> {
+    /// Xavier uniform weights with random seed and zero bias
     pub fn new(input_size: usize, output_size: usize) -> Self {
-        let weight = Matrix::random(output_size, input_size);
-        let bias = Matrix::random(output_size, 1);
+        Self::initialized(input_size, output_size, Initializer::XavierUniform, rand::random())
+    }
+
+    /// layers created with the same initializer and seed are bit-identical
+    pub fn initialized(input_size: usize, output_size: usize, initializer: Initializer, seed: u64) -> Self {
+        let weight = initializer.seeded_matrix(output_size, input_size, seed).cast();
+        let bias = Matrix::zero(output_size, 1);
         Self {
             weight,
             bias,
+            weight_gradient: Matrix::zero(output_size, input_size),
+            bias_gradient: Matrix::zero(output_size, 1),
             input: Matrix::empty()
         }
-    } 
+    }
 }
 
-impl Layer for Dense {
-    fn eval(&self, input: &Matrix) -> MathResult<Matrix> {
-        let mut prod = product(&self.weight, &input)?;
-        prod += &self.bias;
-        Ok(prod)
+impl<T: Scalar> Layer<T> for Dense<T> {
+    /// input is a column-stacked batch with shape (input_size x batch_size)
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let mut output = product(&self.weight, input)?;
+        add_bias(&mut output, &self.bias);
+        Ok(output)
     }
 
-    fn forward(&mut self, input: Matrix) -> MathResult<Matrix> {
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
         self.input = input;
         self.eval(&self.input)
     }
 
-    fn backward(&mut self, output_gradient: &Matrix, learning_rate: f64) -> MathResult<Matrix> {
-        let weight_gradient = product(output_gradient, &self.input.transpose())?;
-        self.weight.sub_assign(&weight_gradient.mul(learning_rate))?;
-        self.bias.sub_assign(&output_gradient.mul(learning_rate))?;
-        self.weight.transpose().product(output_gradient)
+    /// output gradient is expected to be averaged over the batch already,
+    /// so the per-sample gradients are summed here
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let expected = Dimensions::new(self.weight.rows(), self.input.cols());
+        if output_gradient.dimensions() != expected {
+            return Err(MathError::IncorrectMatricesDimensions("dense backward".to_string(), expected, output_gradient.dimensions()));
+        }
+        self.weight_gradient = output_gradient * self.input.transpose_view();
+        self.bias_gradient = output_gradient.sum_axis(Axis::Columns);
+        Ok(self.weight.transpose_view() * output_gradient)
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter { value: &mut self.weight, gradient: &self.weight_gradient },
+            Parameter { value: &mut self.bias, gradient: &self.bias_gradient },
+        ]
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::Dense {
+            input_size: self.weight.cols(),
+            output_size: self.weight.rows(),
+        }
+    }
+
+    fn state(&self) -> Vec<&Matrix<T>> {
+        vec![&self.weight, &self.bias]
+    }
+
+    fn state_mut(&mut self) -> Vec<&mut Matrix<T>> {
+        vec![&mut self.weight, &mut self.bias]
     }
 }
\ No newline at end of file
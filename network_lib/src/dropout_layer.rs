@@ -0,0 +1,82 @@
+use matrix_lib::{
+    errors::*,
+    matrix::*,
+    matrix_functions::*,
+    scalar::Scalar,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+use super::{
+    layer::*,
+    serialization::LayerConfig,
+};
+
+/// Inverted dropout: in training mode every element is zeroed with probability `rate`
+/// and the rest are scaled by `1 / (1 - rate)`, so inference is just the identity
+pub struct Dropout<T: Scalar = f64> {
+    rate: f64,
+    mode: Mode,
+    rng: StdRng,
+    mask: Matrix<T>,
+}
+
+impl<T: Scalar> Dropout<T> {
+    /// mask with random seed, `rate` must be in [0, 1)
+    pub fn new(rate: f64) -> MathResult<Self> {
+        Self::seeded(rate, rand::random())
+    }
+
+    /// layers created with the same seed drop the same elements
+    pub fn seeded(rate: f64, seed: u64) -> MathResult<Self> {
+        if !(0.0..1.0).contains(&rate) {
+            return Err(MathError::InvalidParameter("dropout rate".to_string(), rate));
+        }
+        Ok(Self {
+            rate,
+            mode: Mode::Training,
+            rng: StdRng::seed_from_u64(seed),
+            mask: Matrix::empty(),
+        })
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+}
+
+impl<T: Scalar> Layer<T> for Dropout<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        Ok(input.clone())
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        if self.mode == Mode::Inference {
+            self.mask = Matrix::empty();
+            return Ok(input);
+        }
+        let keep = 1.0 - self.rate;
+        self.mask = Matrix::bernoulli(input.rows(), input.cols(), keep, &mut self.rng)
+            .map(|x| x / keep)
+            .cast();
+        mul(&input, &self.mask)
+    }
+
+    /// passes the gradient through the elements kept by the last forward pass
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        if self.mode == Mode::Inference {
+            Ok(output_gradient.clone())
+        } else {
+            mul(output_gradient, &self.mask)
+        }
+    }
+
+    fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::Dropout { rate: self.rate }
+    }
+}
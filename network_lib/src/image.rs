@@ -0,0 +1,167 @@
+use matrix_lib::{
+    dimensions::Dimensions,
+    errors::*,
+    matrix::Matrix,
+    scalar::Scalar,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Shape of an image stored in a matrix column in channel, row, column order.
+/// A batch of images is a matrix with `size()` rows and a column per image
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageShape {
+    pub channels: usize,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl ImageShape {
+    pub fn new(channels: usize, height: usize, width: usize) -> Self {
+        Self { channels, height, width }
+    }
+
+    /// number of values in a single image
+    pub fn size(&self) -> usize {
+        self.channels * self.height * self.width
+    }
+}
+
+/// Sliding window of convolution and pooling, all pairs are (height, width)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Window {
+    pub kernel: (usize, usize),
+    pub stride: (usize, usize),
+    /// zeros added on both sides of the image
+    pub padding: (usize, usize),
+    /// distance between the kernel elements, 1 is a dense kernel
+    pub dilation: (usize, usize),
+}
+
+impl Window {
+    /// window with stride 1, no padding and dilation
+    pub fn new(kernel_height: usize, kernel_width: usize) -> Self {
+        Self {
+            kernel: (kernel_height, kernel_width),
+            stride: (1, 1),
+            padding: (0, 0),
+            dilation: (1, 1),
+        }
+    }
+
+    pub fn square(kernel: usize) -> Self {
+        Self::new(kernel, kernel)
+    }
+
+    pub fn with_stride(self, stride: usize) -> Self {
+        Self { stride: (stride, stride), ..self }
+    }
+
+    pub fn with_padding(self, padding: usize) -> Self {
+        Self { padding: (padding, padding), ..self }
+    }
+
+    pub fn with_dilation(self, dilation: usize) -> Self {
+        Self { dilation: (dilation, dilation), ..self }
+    }
+
+    /// output height and width, `None` if the window doesn't fit into the padded image
+    pub fn output_size(&self, height: usize, width: usize) -> Option<(usize, usize)> {
+        Some((
+            output_length(height, self.kernel.0, self.stride.0, self.padding.0, self.dilation.0)?,
+            output_length(width, self.kernel.1, self.stride.1, self.padding.1, self.dilation.1)?,
+        ))
+    }
+
+    /// output shape with the same number of channels
+    pub fn output_shape(&self, input: ImageShape) -> Option<ImageShape> {
+        let (height, width) = self.output_size(input.height, input.width)?;
+        Some(ImageShape::new(input.channels, height, width))
+    }
+
+    /// position in the unpadded image of the kernel element `k` at the output position `i` along one axis
+    #[inline(always)]
+    fn source(i: usize, k: usize, stride: usize, padding: usize, dilation: usize, length: usize) -> Option<usize> {
+        (i * stride + k * dilation).checked_sub(padding).filter(|&x| x < length)
+    }
+
+    /// image position of the kernel element (ki, kj) at the output position (oy, ox), `None` for padding
+    #[inline(always)]
+    pub(crate) fn source_position(&self, input: ImageShape, oy: usize, ox: usize, ki: usize, kj: usize) -> Option<usize> {
+        let y = Self::source(oy, ki, self.stride.0, self.padding.0, self.dilation.0, input.height)?;
+        let x = Self::source(ox, kj, self.stride.1, self.padding.1, self.dilation.1, input.width)?;
+        Some(y * input.width + x)
+    }
+}
+
+fn output_length(length: usize, kernel: usize, stride: usize, padding: usize, dilation: usize) -> Option<usize> {
+    if kernel == 0 || stride == 0 || dilation == 0 {
+        return None;
+    }
+    let span = dilation.checked_mul(kernel - 1)?.checked_add(1)?;
+    let padded = padding.checked_mul(2)?.checked_add(length)?;
+    (padded >= span).then(|| (padded - span) / stride + 1)
+}
+
+/// output shape of the windowed layer `name`, fails if the window doesn't fit into the padded input
+pub(crate) fn windowed_shape(name: &str, input: ImageShape, window: &Window) -> MathResult<ImageShape> {
+    window.output_shape(input).ok_or_else(|| MathError::IncorrectMatricesDimensions(
+        name.to_string(),
+        Dimensions::new(input.height, input.width),
+        Dimensions::new(window.kernel.0, window.kernel.1),
+    ))
+}
+
+/// Unfolds every window of the batch into a column, so convolution becomes a single product.
+/// The result has a row per channel and kernel element and a column per output position and image,
+/// columns of the same position are adjacent: `position * batch + image`
+pub(crate) fn im2col<T: Scalar>(input: &Matrix<T>, shape: ImageShape, window: &Window) -> Matrix<T> {
+    let (out_height, out_width) = window.output_size(shape.height, shape.width).unwrap_or((0, 0));
+    let (kernel_height, kernel_width) = window.kernel;
+    let batch = input.cols();
+    let plane = shape.height * shape.width;
+    let mut columns = Matrix::zero(shape.channels * kernel_height * kernel_width, out_height * out_width * batch);
+    for c in 0..shape.channels {
+        for ki in 0..kernel_height {
+            for kj in 0..kernel_width {
+                let row = (c * kernel_height + ki) * kernel_width + kj;
+                for oy in 0..out_height {
+                    for ox in 0..out_width {
+                        if let Some(source) = window.source_position(shape, oy, ox, ki, kj) {
+                            let start = (oy * out_width + ox) * batch;
+                            columns[row][start..start + batch].copy_from_slice(&input[c * plane + source]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    columns
+}
+
+/// Adjoint of `im2col`: sums the unfolded values back into the images they were taken from
+pub(crate) fn col2im<T: Scalar>(columns: &Matrix<T>, shape: ImageShape, window: &Window, batch: usize) -> Matrix<T> {
+    let (out_height, out_width) = window.output_size(shape.height, shape.width).unwrap_or((0, 0));
+    let (kernel_height, kernel_width) = window.kernel;
+    let plane = shape.height * shape.width;
+    let mut images = Matrix::zero(shape.size(), batch);
+    for c in 0..shape.channels {
+        for ki in 0..kernel_height {
+            for kj in 0..kernel_width {
+                let row = (c * kernel_height + ki) * kernel_width + kj;
+                for oy in 0..out_height {
+                    for ox in 0..out_width {
+                        if let Some(source) = window.source_position(shape, oy, ox, ki, kj) {
+                            let start = (oy * out_width + ox) * batch;
+                            let values = &columns[row][start..start + batch];
+                            images[c * plane + source].iter_mut().zip(values).for_each(|(x, v)| *x += *v);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    images
+}
@@ -0,0 +1,99 @@
+use matrix_lib::matrix::Matrix;
+use rand::{
+    Rng,
+    SeedableRng,
+    rngs::StdRng,
+};
+
+/// residual norm below which a column of the orthogonal initializer is redrawn
+const ORTHOGONAL_EPSILON: f64 = 1e-10;
+
+/// Strategy to fill the initial weights of shape (fan_out x fan_in)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Initializer {
+    Zeros,
+    Constant(f64),
+    /// uniform [0, 1), the legacy behaviour of `Matrix::random`
+    Uniform,
+    /// Glorot, uniform in [-limit, limit] where limit = sqrt(6 / (fan_in + fan_out))
+    XavierUniform,
+    /// Glorot, normal with std = sqrt(2 / (fan_in + fan_out))
+    XavierNormal,
+    /// Kaiming, uniform with limit = sqrt(6 / fan_in)
+    HeUniform,
+    /// Kaiming, normal with std = sqrt(2 / fan_in)
+    HeNormal,
+    /// uniform with limit = sqrt(3 / fan_in)
+    LecunUniform,
+    /// normal with std = sqrt(1 / fan_in)
+    LecunNormal,
+    /// (semi-)orthogonal matrix scaled by gain
+    Orthogonal(f64),
+}
+
+impl Initializer {
+    /// matrix with `rows` outputs and `cols` inputs
+    pub fn matrix<R: Rng + ?Sized>(&self, rows: usize, cols: usize, rng: &mut R) -> Matrix {
+        let fan_in = cols.max(1) as f64;
+        let fan_out = rows.max(1) as f64;
+        match *self {
+            Initializer::Zeros => Matrix::zero(rows, cols),
+            Initializer::Constant(value) => Matrix::new(rows, cols, |_, _| value),
+            Initializer::Uniform => Matrix::random_uniform(rows, cols, 0.0, 1.0, rng),
+            Initializer::XavierUniform => uniform(rows, cols, (6.0 / (fan_in + fan_out)).sqrt(), rng),
+            Initializer::XavierNormal => normal(rows, cols, (2.0 / (fan_in + fan_out)).sqrt(), rng),
+            Initializer::HeUniform => uniform(rows, cols, (6.0 / fan_in).sqrt(), rng),
+            Initializer::HeNormal => normal(rows, cols, (2.0 / fan_in).sqrt(), rng),
+            Initializer::LecunUniform => uniform(rows, cols, (3.0 / fan_in).sqrt(), rng),
+            Initializer::LecunNormal => normal(rows, cols, (1.0 / fan_in).sqrt(), rng),
+            Initializer::Orthogonal(gain) => orthogonal(rows, cols, gain, rng),
+        }
+    }
+
+    pub fn seeded_matrix(&self, rows: usize, cols: usize, seed: u64) -> Matrix {
+        self.matrix(rows, cols, &mut StdRng::seed_from_u64(seed))
+    }
+}
+
+fn uniform<R: Rng + ?Sized>(rows: usize, cols: usize, limit: f64, rng: &mut R) -> Matrix {
+    Matrix::random_uniform(rows, cols, -limit, limit, rng)
+}
+
+fn normal<R: Rng + ?Sized>(rows: usize, cols: usize, std: f64, rng: &mut R) -> Matrix {
+    Matrix::random_normal(rows, cols, 0.0, std, rng)
+}
+
+/// orthonormalizes columns of a gaussian matrix with modified Gram-Schmidt,
+/// rows are orthonormal instead when there are less rows than columns.
+/// A column (almost) dependent on the previous ones is redrawn
+fn orthogonal<R: Rng + ?Sized>(rows: usize, cols: usize, gain: f64, rng: &mut R) -> Matrix {
+    let (n, k) = (rows.max(cols), rows.min(cols));
+    let mut q = normal(n, k, 1.0, rng);
+    for j in 0..k {
+        loop {
+            for p in 0..j {
+                let dot: f64 = (0..n).map(|i| q[i][j] * q[i][p]).sum();
+                for i in 0..n {
+                    q[i][j] -= dot * q[i][p];
+                }
+            }
+            let norm = (0..n).map(|i| q[i][j] * q[i][j]).sum::<f64>().sqrt();
+            if norm > ORTHOGONAL_EPSILON {
+                for i in 0..n {
+                    q[i][j] /= norm;
+                }
+                break;
+            }
+            let column = normal(n, 1, 1.0, rng);
+            for i in 0..n {
+                q[i][j] = column[i][0];
+            }
+        }
+    }
+    q.mul_assign(gain);
+    if rows >= cols {
+        q
+    } else {
+        q.transpose()
+    }
+}
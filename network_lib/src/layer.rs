@@ -1,12 +1,62 @@
 use matrix_lib::{
-    errors::MathResult, 
-    matrix::*
+    errors::MathResult,
+    matrix::*,
+    scalar::Scalar,
+    simd,
 };
+use super::serialization::LayerConfig;
 
-pub trait Layer {
-    fn eval(&self, input: &Matrix) -> MathResult<Matrix>;
+/// Trainable parameter of the layer paired with its gradient from the last backward pass
+pub struct Parameter<'a, T: Scalar = f64> {
+    pub value: &'a mut Matrix<T>,
+    pub gradient: &'a Matrix<T>,
+}
 
-    fn forward(&mut self, input: Matrix) -> MathResult<Matrix>;
+/// Behaviour of stochastic layers such as `Dropout` in `forward`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Training,
+    Inference,
+}
 
-    fn backward(&mut self, output_gradient: &Matrix, learning_rate: f64) -> MathResult<Matrix>;
-}
\ No newline at end of file
+/// Network layer over matrices with element type `T`
+pub trait Layer<T: Scalar = f64> {
+    /// always behaves as in inference mode
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>>;
+
+    /// remembers what backward needs, behaviour depends on the mode set by `set_mode`
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>>;
+
+    /// computes gradients of the layer parameters and returns the input gradient
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>>;
+
+    /// layers start in training mode, deterministic layers ignore the mode
+    fn set_mode(&mut self, _mode: Mode) {
+        //
+    }
+
+    /// parameters to be updated by optimizer, the order must be stable between calls
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        Vec::new()
+    }
+
+    /// architecture description used to save the layer
+    fn config(&self) -> LayerConfig;
+
+    /// matrices to be saved along with the config, the order must match `state_mut`
+    fn state(&self) -> Vec<&Matrix<T>> {
+        Vec::new()
+    }
+
+    fn state_mut(&mut self) -> Vec<&mut Matrix<T>> {
+        Vec::new()
+    }
+}
+
+/// adds the bias column to every column of the output in place
+pub(crate) fn add_bias<T: Scalar>(output: &mut Matrix<T>, bias: &Matrix<T>) {
+    debug_assert_eq!(output.rows(), bias.rows(), "bias has a row per output row");
+    for i in 0..output.rows() {
+        simd::add_scalar(&mut output[i], bias[i][0]);
+    }
+}
@@ -0,0 +1,111 @@
+use matrix_lib::{
+    errors::*,
+    matrix::*,
+    matrix_functions::*,
+    matrix_reductions::Axis,
+    scalar::Scalar,
+};
+use super::{
+    layer::*,
+    serialization::LayerConfig,
+};
+
+const DEFAULT_EPSILON: f64 = 1e-5;
+
+/// Normalizes every sample (column) over its features followed by learnable per-feature scale `gamma` and shift `beta`.
+/// Statistics don't depend on the batch, so training and inference behave the same
+pub struct LayerNorm<T: Scalar = f64> {
+    gamma: Matrix<T>,
+    beta: Matrix<T>,
+    gamma_gradient: Matrix<T>,
+    beta_gradient: Matrix<T>,
+    epsilon: f64,
+    /// normalized input and `1 / sqrt(variance + epsilon)` of every sample from the last forward pass
+    normalized: Matrix<T>,
+    inv_std: Matrix<T>,
+}
+
+impl<T: Scalar> LayerNorm<T> {
+    pub fn new(size: usize) -> Self {
+        Self::configured(size, DEFAULT_EPSILON).expect("default epsilon is valid")
+    }
+
+    /// `epsilon` is added to the variance and must be positive
+    pub fn configured(size: usize, epsilon: f64) -> MathResult<Self> {
+        if !(epsilon > 0.0 && epsilon.is_finite()) {
+            return Err(MathError::InvalidParameter("layer norm epsilon".to_string(), epsilon));
+        }
+        Ok(Self {
+            gamma: Matrix::new(size, 1, |_, _| T::ONE),
+            beta: Matrix::zero(size, 1),
+            gamma_gradient: Matrix::zero(size, 1),
+            beta_gradient: Matrix::zero(size, 1),
+            epsilon,
+            normalized: Matrix::empty(),
+            inv_std: Matrix::empty(),
+        })
+    }
+
+    /// returns normalized input, inverse standard deviations of the samples and the output
+    fn normalize(&self, input: &Matrix<T>) -> MathResult<(Matrix<T>, Matrix<T>, Matrix<T>)> {
+        if input.rows() != self.gamma.rows() {
+            return Err(MathError::IncorrectMatricesDimensions("layer norm".to_string(), self.gamma.dimensions(), input.dimensions()));
+        }
+        let epsilon = T::from_f64(self.epsilon);
+        let inv_std = input.variance_axis(Axis::Rows)?.map(|v| T::ONE / (v + epsilon).sqrt());
+        let normalized = broadcast_mul(&broadcast_sub(input, &input.mean_axis(Axis::Rows)?)?, &inv_std)?;
+        let output = broadcast_add(&broadcast_mul(&normalized, &self.gamma)?, &self.beta)?;
+        Ok((normalized, inv_std, output))
+    }
+}
+
+impl<T: Scalar> Layer<T> for LayerNorm<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let (_, _, output) = self.normalize(input)?;
+        Ok(output)
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        let (normalized, inv_std, output) = self.normalize(&input)?;
+        self.normalized = normalized;
+        self.inv_std = inv_std;
+        Ok(output)
+    }
+
+    /// for every sample with `N` features and `g = gamma * dy` the input gradient is
+    /// `inv_std / N * (N * g - sum(g) - x_hat * sum(g * x_hat))`
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        if !self.normalized.is_same_size(output_gradient) {
+            return Err(MathError::IncorrectMatricesDimensions("layer norm backward".to_string(), self.normalized.dimensions(), output_gradient.dimensions()));
+        }
+        self.beta_gradient = output_gradient.sum_axis(Axis::Columns);
+        self.gamma_gradient = mul(output_gradient, &self.normalized)?.sum_axis(Axis::Columns);
+        let features = T::from_f64(output_gradient.rows() as f64);
+        let scaled = broadcast_mul(output_gradient, &self.gamma)?;
+        let centered = broadcast_sub(&(&scaled * features), &scaled.sum_axis(Axis::Rows))?;
+        let projection = broadcast_mul(&self.normalized, &mul(&scaled, &self.normalized)?.sum_axis(Axis::Rows))?;
+        broadcast_mul(&(centered - &projection), &(&self.inv_std / features))
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter { value: &mut self.gamma, gradient: &self.gamma_gradient },
+            Parameter { value: &mut self.beta, gradient: &self.beta_gradient },
+        ]
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::LayerNorm {
+            size: self.gamma.rows(),
+            epsilon: self.epsilon,
+        }
+    }
+
+    fn state(&self) -> Vec<&Matrix<T>> {
+        vec![&self.gamma, &self.beta]
+    }
+
+    fn state_mut(&mut self) -> Vec<&mut Matrix<T>> {
+        vec![&mut self.gamma, &mut self.beta]
+    }
+}
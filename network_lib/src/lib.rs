@@ -1,5 +1,16 @@
 pub mod layer;
+pub mod initializer;
 pub mod dense_layer;
+pub mod image;
+pub mod conv_layer;
+pub mod pooling_layer;
 pub mod activation_layer;
+pub mod softmax_layer;
+pub mod dropout_layer;
+pub mod batch_norm_layer;
+pub mod layer_norm_layer;
 pub mod data_source;
-pub mod network;
\ No newline at end of file
+pub mod optimizer;
+pub mod loss;
+pub mod network;
+pub mod serialization;
\ No newline at end of file
@@ -0,0 +1,202 @@
+use matrix_lib::{
+    errors::*,
+    matrix::*,
+    matrix_functions::{
+        log_sum_exp,
+        softmax,
+    },
+    scalar::Scalar,
+};
+
+/// lower bound for probabilities passed to logarithm
+const PROBABILITY_EPSILON: f64 = 1e-12;
+
+/// Loss function of the network output.
+/// Matrices are column-stacked batches, the value is averaged over the batch
+/// and the gradient is taken with respect to the predicted matrix.
+/// The value is reported as `f64` for any element type
+pub trait Loss<T: Scalar = f64> {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64>;
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>>;
+}
+
+#[inline]
+fn batch_size<T: Scalar>(matrix: &Matrix<T>) -> T {
+    T::from_f64(matrix.cols().max(1) as f64)
+}
+
+#[inline]
+fn size<T: Scalar>(matrix: &Matrix<T>) -> T {
+    T::from_f64(matrix.dimensions().size() as f64)
+}
+
+/// keeps probabilities away from 0 and 1, the bound is never below the machine epsilon of `T`
+#[inline]
+fn clamp_probability<T: Scalar>(p: T) -> T {
+    let epsilon = T::from_f64(PROBABILITY_EPSILON).max(T::EPSILON);
+    p.max(epsilon).min(T::ONE - epsilon)
+}
+
+/// Mean squared error
+pub struct Mse;
+
+impl<T: Scalar> Loss<T> for Mse {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64> {
+        Ok(
+            map(expected, predicted, |y, p| (p - y).powi(2))?.mean().to_f64()
+        )
+    }
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let size = size(expected);
+        let two = T::from_f64(2.0);
+        map(expected, predicted, |y, p| two * (p - y) / size)
+    }
+}
+
+/// Mean absolute error
+pub struct Mae;
+
+impl<T: Scalar> Loss<T> for Mae {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64> {
+        Ok(
+            map(expected, predicted, |y, p| (p - y).abs())?.mean().to_f64()
+        )
+    }
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let size = size(expected);
+        // subgradient 0 at the minimum, signum would give ±1 for exact predictions
+        map(expected, predicted, |y, p| if p == y { T::ZERO } else { (p - y).signum() / size })
+    }
+}
+
+/// Quadratic for errors smaller than delta and linear otherwise
+pub struct Huber {
+    delta: f64,
+}
+
+impl Huber {
+    /// `delta` must be positive
+    pub fn new(delta: f64) -> MathResult<Self> {
+        if delta.is_nan() || delta <= 0.0 {
+            return Err(MathError::InvalidParameter("huber delta".to_string(), delta));
+        }
+        Ok(Self { delta })
+    }
+}
+
+impl<T: Scalar> Loss<T> for Huber {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64> {
+        let delta = T::from_f64(self.delta);
+        let half = T::from_f64(0.5);
+        let matrix = map(expected, predicted, |y, p| {
+            let error = (p - y).abs();
+            if error <= delta {
+                half * error * error
+            } else {
+                delta * (error - half * delta)
+            }
+        })?;
+        Ok(matrix.mean().to_f64())
+    }
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let delta = T::from_f64(self.delta);
+        let size = size(expected);
+        map(expected, predicted, |y, p| (p - y).max(-delta).min(delta) / size)
+    }
+}
+
+/// Binary cross-entropy for predicted probabilities in (0, 1)
+pub struct BinaryCrossEntropy;
+
+impl<T: Scalar> Loss<T> for BinaryCrossEntropy {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64> {
+        let matrix = map(expected, predicted, |y, p| {
+            let p = clamp_probability(p);
+            -(y * p.ln() + (T::ONE - y) * (T::ONE - p).ln())
+        })?;
+        Ok(matrix.mean().to_f64())
+    }
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let size = size(expected);
+        map(expected, predicted, |y, p| {
+            let p = clamp_probability(p);
+            (p - y) / (p * (T::ONE - p)) / size
+        })
+    }
+}
+
+/// Categorical cross-entropy for predicted class probabilities,
+/// each column of the batch is a distribution over classes
+pub struct CategoricalCrossEntropy;
+
+impl<T: Scalar> Loss<T> for CategoricalCrossEntropy {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64> {
+        let matrix = map(expected, predicted, |y, p| -y * clamp_probability(p).ln())?;
+        Ok((matrix.sum() / batch_size(&matrix)).to_f64())
+    }
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let batch = batch_size(expected);
+        map(expected, predicted, |y, p| -y / clamp_probability(p) / batch)
+    }
+}
+
+/// Categorical cross-entropy fused with softmax.
+/// Expects raw scores (logits) instead of probabilities, so the network
+/// shouldn't end with softmax. The gradient reduces to `softmax(x) - y`
+pub struct SoftmaxCrossEntropy;
+
+impl<T: Scalar> Loss<T> for SoftmaxCrossEntropy {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64> {
+        check_dimensions(expected, predicted)?;
+        let log_sum_exp = log_sum_exp(predicted);
+        let mut sum = T::ZERO;
+        for j in 0..predicted.cols() {
+            for i in 0..predicted.rows() {
+                sum += expected[i][j] * (log_sum_exp[0][j] - predicted[i][j]);
+            }
+        }
+        Ok((sum / batch_size(predicted)).to_f64())
+    }
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>> {
+        check_dimensions(expected, predicted)?;
+        let batch = batch_size(predicted);
+        let mut gradient = softmax(predicted);
+        for i in 0..gradient.rows() {
+            for j in 0..gradient.cols() {
+                gradient[i][j] = (gradient[i][j] - expected[i][j]) / batch;
+            }
+        }
+        Ok(gradient)
+    }
+}
+
+/// Hinge loss for labels in {-1, 1}
+pub struct Hinge;
+
+impl<T: Scalar> Loss<T> for Hinge {
+    fn value(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<f64> {
+        Ok(
+            map(expected, predicted, |y, p| (T::ONE - y * p).max(T::ZERO))?.mean().to_f64()
+        )
+    }
+
+    fn gradient(&self, expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let size = size(expected);
+        map(expected, predicted, |y, p| if y * p < T::ONE { -y / size } else { T::ZERO })
+    }
+}
+
+fn check_dimensions<T: Scalar>(expected: &Matrix<T>, predicted: &Matrix<T>) -> MathResult<()> {
+    if expected.is_same_size(predicted) {
+        Ok(())
+    } else {
+        Err(MathError::IncorrectMatricesDimensions("loss".to_string(), expected.dimensions(), predicted.dimensions()))
+    }
+}
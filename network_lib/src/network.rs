@@ -1,67 +1,124 @@
 use super::{
-    layer::Layer,
+    layer::*,
     data_source::TrainDataSource,
+    optimizer::Optimizer,
+    loss::Loss,
+    serialization::*,
 };
+use std::path::Path;
 use matrix_lib::{
     matrix::Matrix,
-    matrix_functions::*,
-    errors::MathResult,
+    errors::*,
+    scalar::Scalar,
 };
 
-pub struct FeedforwardNetwork {
-    layers: Vec<Box<dyn Layer>>,
+/// Sequence of layers with element type `T`, `f64` by default
+pub struct FeedforwardNetwork<T: Scalar = f64> {
+    layers: Vec<Box<dyn Layer<T>>>,
+    mode: Mode,
 }
 
-impl FeedforwardNetwork {
-    pub fn new(layers: Vec<Box<dyn Layer>>) -> Self {
-        Self {
+impl<T: Scalar> FeedforwardNetwork<T> {
+    pub fn new(layers: Vec<Box<dyn Layer<T>>>) -> Self {
+        let mut network = Self {
             layers,
-        }
+            mode: Mode::Training,
+        };
+        network.set_mode(Mode::Training);
+        network
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// switches all layers, `train` always runs in training mode and restores the previous one afterwards
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+        self.layers.iter_mut().for_each(|layer| layer.set_mode(mode));
+    }
+
+    /// Saves the layers architecture and weights into the directory,
+    /// see `serialization` module for the format details
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> SerializationResult<()> {
+        save_layers(&self.layers, path.as_ref())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> SerializationResult<Self> {
+        let layers = load_layers(path.as_ref())?;
+        Ok(Self::new(layers))
     }
 
     // TODO: implement as function call
-    pub fn eval(&self, input: &Matrix) -> MathResult<Matrix> {
+    pub fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
         let mut output = input.clone();
-        for layer in self.layers.iter() {
-            output = layer.eval(&output)?;
+        for (index, layer) in self.layers.iter().enumerate() {
+            output = layer.eval(&output).map_err(|error| error.in_layer(index))?;
         }
         Ok(output)
     }
 
-    pub fn train(&mut self, epochs: usize, learning_rate: f64, data_source: &TrainDataSource) -> MathResult<f64> {
+    /// Runs the layers in the current mode and keeps their caches for the backward pass.
+    /// In training mode stochastic layers like `Dropout` are applied and `BatchNorm` updates
+    /// its running statistics, in inference mode the result is the same as `eval`
+    pub fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        let mut output = input;
+        for (index, layer) in self.layers.iter_mut().enumerate() {
+            output = layer.forward(output).map_err(|error| error.in_layer(index))?;
+            if !output.is_finite() {
+                return Err(MathError::NotFinite("forward".to_string()).in_layer(index));
+            }
+        }
+        Ok(output)
+    }
+
+    /// Trains the network with mini-batch gradient descent.
+    /// Each batch is fed as a column-stacked matrix and the weights are updated once per batch
+    /// with the gradient averaged over its samples. Returns the mean loss of the last epoch.
+    /// Training stops with `NotFinite` error once a layer output or the loss diverges to NaN or infinity
+    pub fn train(&mut self, epochs: usize, loss: &dyn Loss<T>, optimizer: &mut dyn Optimizer<T>, batch_size: usize, data_source: &TrainDataSource<T>) -> MathResult<f64> {
+        let mode = self.mode;
+        self.set_mode(Mode::Training);
+        let result = self.train_epochs(epochs, loss, optimizer, batch_size, data_source);
+        self.set_mode(mode);
+        result
+    }
+
+    fn train_epochs(&mut self, epochs: usize, loss: &dyn Loss<T>, optimizer: &mut dyn Optimizer<T>, batch_size: usize, data_source: &TrainDataSource<T>) -> MathResult<f64> {
+        let batches = data_source.batches(batch_size)?;
+        if batches.is_empty() {
+            return Err(MathError::EmptyMatrix("train".to_string()));
+        }
         let mut global_error = f64::NAN;
         for _ in 0..epochs {
             let mut error: f64 = 0.0;
-            let data = data_source.content();
-            for item in data {
-                let mut output = item.input.clone();
-                for layer in self.layers.iter_mut() {
-                    output = layer.forward(output)?;
+            for batch in batches.iter() {
+                let output = self.forward(batch.input.clone())?;
+                let value = loss.value(&batch.output, &output)?;
+                if !value.is_finite() {
+                    return Err(MathError::NotFinite("loss".to_string()));
                 }
-                error += mse(&item.output, &output)?;
-                let mut grad = mse_prime(&item.output, &output)?;
-                for layer in self.layers.iter_mut().rev() {
-                    grad = layer.backward(&grad, learning_rate)?;
+                error += value;
+                let mut grad = loss.gradient(&batch.output, &output)?;
+                for (index, layer) in self.layers.iter_mut().enumerate().rev() {
+                    grad = layer.backward(&grad).map_err(|error| error.in_layer(index))?;
                 }
-                error /= item.input.dimensions().size() as f64;
+                self.update_parameters(optimizer)?;
             }
-            global_error = error;
+            global_error = error / batches.len() as f64;
         }
         Ok(global_error)
     }
-}
 
-fn mse(a: &Matrix, b: &Matrix) -> MathResult<f64> {
-    Ok(
-        sub(a, b)?
-            .powi(2)
-            .mean()
-    )
-}
-
-fn mse_prime(a: &Matrix, b: &Matrix) -> MathResult<Matrix> {
-    let mut tmp = b.sub(a)?;
-    let dim = tmp.dimensions();
-    tmp *= 2.0 / dim.size() as f64;
-    Ok(tmp)
+    fn update_parameters(&mut self, optimizer: &mut dyn Optimizer<T>) -> MathResult<()> {
+        optimizer.next_step();
+        let mut id = 0;
+        for (index, layer) in self.layers.iter_mut().enumerate() {
+            for parameter in layer.parameters() {
+                optimizer.update(id, parameter.value, parameter.gradient).map_err(|error| error.in_layer(index))?;
+                id += 1;
+            }
+        }
+        Ok(())
+    }
 }
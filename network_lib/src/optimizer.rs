@@ -0,0 +1,217 @@
+use matrix_lib::{
+    errors::*,
+    matrix::Matrix,
+    scalar::Scalar,
+};
+
+const EPSILON: f64 = 1e-8;
+
+/// Updates trainable parameters using their gradients.
+/// Each parameter is identified by `id` which is stable between training steps,
+/// so optimizers can keep per-parameter state such as velocity or moments.
+/// Hyperparameters are `f64` and converted to the element type on update
+pub trait Optimizer<T: Scalar = f64> {
+    /// called once per batch before the parameters are updated
+    fn next_step(&mut self) {
+        //
+    }
+
+    fn update(&mut self, id: usize, parameter: &mut Matrix<T>, gradient: &Matrix<T>) -> MathResult<()>;
+}
+
+fn check_dimensions<T: Scalar>(parameter: &Matrix<T>, gradient: &Matrix<T>) -> MathResult<()> {
+    if parameter.is_same_size(gradient) {
+        Ok(())
+    } else {
+        Err(MathError::IncorrectMatricesDimensions("optimizer update".to_string(), parameter.dimensions(), gradient.dimensions()))
+    }
+}
+
+/// returns zero-initialized state for parameter with given id
+fn state<'a, T: Scalar>(states: &'a mut Vec<Matrix<T>>, id: usize, parameter: &Matrix<T>) -> &'a mut Matrix<T> {
+    if states.len() <= id {
+        states.resize_with(id + 1, Matrix::empty);
+    }
+    if !states[id].is_same_size(parameter) {
+        states[id] = Matrix::zero(parameter.rows(), parameter.cols());
+    }
+    &mut states[id]
+}
+
+/// Stochastic gradient descent with optional classic or Nesterov momentum
+pub struct Sgd<T: Scalar = f64> {
+    learning_rate: f64,
+    momentum: f64,
+    nesterov: bool,
+    velocities: Vec<Matrix<T>>,
+}
+
+impl<T: Scalar> Sgd<T> {
+    pub fn new(learning_rate: f64) -> Self {
+        Self::momentum(learning_rate, 0.0)
+    }
+
+    pub fn momentum(learning_rate: f64, momentum: f64) -> Self {
+        Self {
+            learning_rate,
+            momentum,
+            nesterov: false,
+            velocities: Vec::new(),
+        }
+    }
+
+    pub fn nesterov(learning_rate: f64, momentum: f64) -> Self {
+        Self {
+            nesterov: true,
+            ..Self::momentum(learning_rate, momentum)
+        }
+    }
+}
+
+impl<T: Scalar> Optimizer<T> for Sgd<T> {
+    fn update(&mut self, id: usize, parameter: &mut Matrix<T>, gradient: &Matrix<T>) -> MathResult<()> {
+        check_dimensions(parameter, gradient)?;
+        let (learning_rate, momentum) = (T::from_f64(self.learning_rate), T::from_f64(self.momentum));
+        let velocity = state(&mut self.velocities, id, parameter);
+        for i in 0..parameter.rows() {
+            for j in 0..parameter.cols() {
+                let g = gradient[i][j];
+                let v = momentum * velocity[i][j] - learning_rate * g;
+                velocity[i][j] = v;
+                parameter[i][j] += if self.nesterov {
+                    momentum * v - learning_rate * g
+                } else {
+                    v
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Divides the learning rate by a moving average of squared gradients
+pub struct RmsProp<T: Scalar = f64> {
+    learning_rate: f64,
+    decay: f64,
+    mean_squares: Vec<Matrix<T>>,
+}
+
+impl<T: Scalar> RmsProp<T> {
+    pub fn new(learning_rate: f64, decay: f64) -> Self {
+        Self {
+            learning_rate,
+            decay,
+            mean_squares: Vec::new(),
+        }
+    }
+}
+
+impl<T: Scalar> Optimizer<T> for RmsProp<T> {
+    fn update(&mut self, id: usize, parameter: &mut Matrix<T>, gradient: &Matrix<T>) -> MathResult<()> {
+        check_dimensions(parameter, gradient)?;
+        let (learning_rate, decay, epsilon) = (T::from_f64(self.learning_rate), T::from_f64(self.decay), T::from_f64(EPSILON));
+        let mean_square = state(&mut self.mean_squares, id, parameter);
+        for i in 0..parameter.rows() {
+            for j in 0..parameter.cols() {
+                let g = gradient[i][j];
+                let s = decay * mean_square[i][j] + (T::ONE - decay) * g * g;
+                mean_square[i][j] = s;
+                parameter[i][j] -= learning_rate * g / (s.sqrt() + epsilon);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Scales the learning rate by the accumulated sum of squared gradients
+pub struct AdaGrad<T: Scalar = f64> {
+    learning_rate: f64,
+    sum_squares: Vec<Matrix<T>>,
+}
+
+impl<T: Scalar> AdaGrad<T> {
+    pub fn new(learning_rate: f64) -> Self {
+        Self {
+            learning_rate,
+            sum_squares: Vec::new(),
+        }
+    }
+}
+
+impl<T: Scalar> Optimizer<T> for AdaGrad<T> {
+    fn update(&mut self, id: usize, parameter: &mut Matrix<T>, gradient: &Matrix<T>) -> MathResult<()> {
+        check_dimensions(parameter, gradient)?;
+        let (learning_rate, epsilon) = (T::from_f64(self.learning_rate), T::from_f64(EPSILON));
+        let sum_square = state(&mut self.sum_squares, id, parameter);
+        for i in 0..parameter.rows() {
+            for j in 0..parameter.cols() {
+                let g = gradient[i][j];
+                let s = sum_square[i][j] + g * g;
+                sum_square[i][j] = s;
+                parameter[i][j] -= learning_rate * g / (s.sqrt() + epsilon);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adam with bias-corrected moment estimates.
+/// Non-zero weight decay turns it into AdamW where the decay is decoupled from the gradient
+pub struct Adam<T: Scalar = f64> {
+    learning_rate: f64,
+    beta1: f64,
+    beta2: f64,
+    weight_decay: f64,
+    step: i32,
+    first_moments: Vec<Matrix<T>>,
+    second_moments: Vec<Matrix<T>>,
+}
+
+impl<T: Scalar> Adam<T> {
+    pub fn new(learning_rate: f64, beta1: f64, beta2: f64) -> Self {
+        Self::adamw(learning_rate, beta1, beta2, 0.0)
+    }
+
+    pub fn adamw(learning_rate: f64, beta1: f64, beta2: f64, weight_decay: f64) -> Self {
+        Self {
+            learning_rate,
+            beta1,
+            beta2,
+            weight_decay,
+            step: 0,
+            first_moments: Vec::new(),
+            second_moments: Vec::new(),
+        }
+    }
+}
+
+impl<T: Scalar> Optimizer<T> for Adam<T> {
+    fn next_step(&mut self) {
+        self.step += 1;
+    }
+
+    fn update(&mut self, id: usize, parameter: &mut Matrix<T>, gradient: &Matrix<T>) -> MathResult<()> {
+        check_dimensions(parameter, gradient)?;
+        let step = self.step.max(1);
+        let first_correction = T::from_f64(1.0 - self.beta1.powi(step));
+        let second_correction = T::from_f64(1.0 - self.beta2.powi(step));
+        let (beta1, beta2) = (T::from_f64(self.beta1), T::from_f64(self.beta2));
+        let (learning_rate, weight_decay, epsilon) = (T::from_f64(self.learning_rate), T::from_f64(self.weight_decay), T::from_f64(EPSILON));
+        let first_moment = state(&mut self.first_moments, id, parameter);
+        let second_moment = state(&mut self.second_moments, id, parameter);
+        for i in 0..parameter.rows() {
+            for j in 0..parameter.cols() {
+                let g = gradient[i][j];
+                let m = beta1 * first_moment[i][j] + (T::ONE - beta1) * g;
+                let v = beta2 * second_moment[i][j] + (T::ONE - beta2) * g * g;
+                first_moment[i][j] = m;
+                second_moment[i][j] = v;
+                let m_hat = m / first_correction;
+                let v_hat = v / second_correction;
+                let p = parameter[i][j];
+                parameter[i][j] = p - learning_rate * (m_hat / (v_hat.sqrt() + epsilon) + weight_decay * p);
+            }
+        }
+        Ok(())
+    }
+}
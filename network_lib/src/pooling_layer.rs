@@ -0,0 +1,260 @@
+use std::marker::PhantomData;
+use matrix_lib::{
+    dimensions::Dimensions,
+    errors::*,
+    matrix::*,
+    scalar::Scalar,
+};
+use super::{
+    image::*,
+    layer::*,
+    serialization::LayerConfig,
+};
+
+/// Maximum of every window of every channel, padding never wins.
+/// Positions of the maxima are remembered in `forward` to route the gradient back
+pub struct MaxPool2D<T: Scalar = f64> {
+    input_shape: ImageShape,
+    output_shape: ImageShape,
+    window: Window,
+    /// input row of the maximum for every output element, `None` if the window covers only padding
+    argmax: Vec<Option<usize>>,
+    batch: usize,
+    _scalar: PhantomData<T>,
+}
+
+impl<T: Scalar> MaxPool2D<T> {
+    pub fn new(input_shape: ImageShape, window: Window) -> MathResult<Self> {
+        Ok(Self {
+            input_shape,
+            output_shape: windowed_shape("max pool", input_shape, &window)?,
+            window,
+            argmax: Vec::new(),
+            batch: 0,
+            _scalar: PhantomData,
+        })
+    }
+
+    pub fn output_shape(&self) -> ImageShape {
+        self.output_shape
+    }
+
+    /// input row of the maximum for every output element and image
+    fn argmax(&self, input: &Matrix<T>) -> MathResult<Vec<Option<usize>>> {
+        check_input("max pool", self.input_shape, input)?;
+        let batch = input.cols();
+        let mut argmax: Vec<Option<usize>> = vec![None; self.output_shape.size() * batch];
+        for_each_source(self.input_shape, self.output_shape, &self.window, |output, source| {
+            for b in 0..batch {
+                let best = &mut argmax[output * batch + b];
+                match *best {
+                    Some(row) if input[row][b] >= input[source][b] => {}
+                    _ => *best = Some(source),
+                }
+            }
+        });
+        Ok(argmax)
+    }
+
+    fn gather(&self, input: &Matrix<T>, argmax: &[Option<usize>]) -> Matrix<T> {
+        let batch = input.cols();
+        Matrix::new(self.output_shape.size(), batch, |i, b| argmax[i * batch + b].map_or(T::ZERO, |row| input[row][b]))
+    }
+}
+
+impl<T: Scalar> Layer<T> for MaxPool2D<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let argmax = self.argmax(input)?;
+        Ok(self.gather(input, &argmax))
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        self.argmax = self.argmax(&input)?;
+        self.batch = input.cols();
+        Ok(self.gather(&input, &self.argmax))
+    }
+
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        check_gradient("max pool backward", self.output_shape, self.batch, output_gradient)?;
+        let mut input_gradient = Matrix::zero(self.input_shape.size(), self.batch);
+        for i in 0..output_gradient.rows() {
+            for b in 0..self.batch {
+                if let Some(row) = self.argmax[i * self.batch + b] {
+                    input_gradient[row][b] += output_gradient[i][b];
+                }
+            }
+        }
+        Ok(input_gradient)
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::MaxPool2D {
+            input_shape: self.input_shape,
+            window: self.window,
+        }
+    }
+}
+
+/// Mean of every window of every channel, padding counts as zeros,
+/// so every output is divided by the kernel area
+pub struct AvgPool2D<T: Scalar = f64> {
+    input_shape: ImageShape,
+    output_shape: ImageShape,
+    window: Window,
+    batch: usize,
+    _scalar: PhantomData<T>,
+}
+
+impl<T: Scalar> AvgPool2D<T> {
+    pub fn new(input_shape: ImageShape, window: Window) -> MathResult<Self> {
+        Ok(Self {
+            input_shape,
+            output_shape: windowed_shape("average pool", input_shape, &window)?,
+            window,
+            batch: 0,
+            _scalar: PhantomData,
+        })
+    }
+
+    pub fn output_shape(&self) -> ImageShape {
+        self.output_shape
+    }
+
+    fn area(&self) -> T {
+        T::from_f64((self.window.kernel.0 * self.window.kernel.1) as f64)
+    }
+}
+
+impl<T: Scalar> Layer<T> for AvgPool2D<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        check_input("average pool", self.input_shape, input)?;
+        let mut output = Matrix::zero(self.output_shape.size(), input.cols());
+        for_each_source(self.input_shape, self.output_shape, &self.window, |i, source| {
+            output[i].iter_mut().zip(&input[source]).for_each(|(y, x)| *y += *x);
+        });
+        let area = self.area();
+        Ok(output.map(|x| x / area))
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        let output = self.eval(&input)?;
+        self.batch = input.cols();
+        Ok(output)
+    }
+
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        check_gradient("average pool backward", self.output_shape, self.batch, output_gradient)?;
+        let area = self.area();
+        let mut input_gradient = Matrix::zero(self.input_shape.size(), self.batch);
+        for_each_source(self.input_shape, self.output_shape, &self.window, |i, source| {
+            input_gradient[source].iter_mut().zip(&output_gradient[i]).for_each(|(x, y)| *x += *y / area);
+        });
+        Ok(input_gradient)
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::AvgPool2D {
+            input_shape: self.input_shape,
+            window: self.window,
+        }
+    }
+}
+
+/// Mean of every channel over the whole image, the output has a row per channel
+pub struct GlobalAveragePool<T: Scalar = f64> {
+    input_shape: ImageShape,
+    batch: usize,
+    _scalar: PhantomData<T>,
+}
+
+impl<T: Scalar> GlobalAveragePool<T> {
+    /// fails if the plane is empty, its average is undefined
+    pub fn new(input_shape: ImageShape) -> MathResult<Self> {
+        if input_shape.height == 0 || input_shape.width == 0 {
+            return Err(MathError::IncorrectMatricesDimensions(
+                "global average pool".to_string(),
+                Dimensions::new(input_shape.height, input_shape.width),
+                Dimensions::new(1, 1),
+            ));
+        }
+        Ok(Self {
+            input_shape,
+            batch: 0,
+            _scalar: PhantomData,
+        })
+    }
+
+    pub fn output_shape(&self) -> ImageShape {
+        ImageShape::new(self.input_shape.channels, 1, 1)
+    }
+
+    fn plane(&self) -> usize {
+        self.input_shape.height * self.input_shape.width
+    }
+}
+
+impl<T: Scalar> Layer<T> for GlobalAveragePool<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        check_input("global average pool", self.input_shape, input)?;
+        let plane = self.plane();
+        let area = T::from_f64(plane as f64);
+        Ok(Matrix::new(self.input_shape.channels, input.cols(), |c, b| {
+            (c * plane..(c + 1) * plane).fold(T::ZERO, |sum, i| sum + input[i][b]) / area
+        }))
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        let output = self.eval(&input)?;
+        self.batch = input.cols();
+        Ok(output)
+    }
+
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        check_gradient("global average pool backward", self.output_shape(), self.batch, output_gradient)?;
+        let plane = self.plane();
+        let area = T::from_f64(plane as f64);
+        Ok(Matrix::new(self.input_shape.size(), self.batch, |i, b| output_gradient[i / plane][b] / area))
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::GlobalAveragePool {
+            input_shape: self.input_shape,
+        }
+    }
+}
+
+fn check_input<T: Scalar>(name: &str, shape: ImageShape, input: &Matrix<T>) -> MathResult<()> {
+    if input.rows() == shape.size() {
+        Ok(())
+    } else {
+        Err(MathError::IncorrectMatricesDimensions(name.to_string(), Dimensions::new(shape.size(), input.cols()), input.dimensions()))
+    }
+}
+
+fn check_gradient<T: Scalar>(name: &str, shape: ImageShape, batch: usize, gradient: &Matrix<T>) -> MathResult<()> {
+    let expected = Dimensions::new(shape.size(), batch);
+    if gradient.dimensions() == expected {
+        Ok(())
+    } else {
+        Err(MathError::IncorrectMatricesDimensions(name.to_string(), expected, gradient.dimensions()))
+    }
+}
+
+/// calls `visit(output row, input row)` for every unpadded element of every window of every channel
+fn for_each_source<F: FnMut(usize, usize)>(input: ImageShape, output: ImageShape, window: &Window, mut visit: F) {
+    let plane = input.height * input.width;
+    for c in 0..input.channels {
+        for oy in 0..output.height {
+            for ox in 0..output.width {
+                let row = (c * output.height + oy) * output.width + ox;
+                for ki in 0..window.kernel.0 {
+                    for kj in 0..window.kernel.1 {
+                        if let Some(source) = window.source_position(input, oy, ox, ki, kj) {
+                            visit(row, c * plane + source);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
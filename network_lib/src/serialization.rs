@@ -0,0 +1,263 @@
+use std::{
+    error::Error,
+    fmt,
+    fs,
+    io,
+    path::Path,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use matrix_lib::{
+    dimensions::Dimensions,
+    errors::*,
+    matrix::Matrix,
+    scalar::Scalar,
+};
+use super::{
+    activation_layer::*,
+    batch_norm_layer::BatchNorm,
+    conv_layer::Conv2D,
+    dense_layer::Dense,
+    dropout_layer::Dropout,
+    image::*,
+    initializer::Initializer,
+    layer::Layer,
+    layer_norm_layer::LayerNorm,
+    pooling_layer::*,
+    softmax_layer::Softmax,
+};
+
+/// Version of the on-disk model format, increment on incompatible changes
+pub const FORMAT_VERSION: u32 = 1;
+pub const MANIFEST_FILE: &str = "manifest.json";
+pub const TENSORS_FILE: &str = "tensors.bin";
+
+const TENSORS_MAGIC: &[u8; 4] = b"NLTB";
+
+/// Architecture of the layer, enough to recreate it before loading the tensors
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum LayerConfig {
+    Dense { input_size: usize, output_size: usize },
+    Activation { function: ActivationFunction },
+    Softmax,
+    Dropout { rate: f64 },
+    BatchNorm { size: usize, momentum: f64, epsilon: f64 },
+    LayerNorm { size: usize, epsilon: f64 },
+    Conv2D { input_shape: ImageShape, output_channels: usize, window: Window },
+    MaxPool2D { input_shape: ImageShape, window: Window },
+    AvgPool2D { input_shape: ImageShape, window: Window },
+    GlobalAveragePool { input_shape: ImageShape },
+}
+
+impl LayerConfig {
+    /// fails if the config holds parameters the layer rejects, e.g. a tampered manifest
+    pub fn build<T: Scalar>(&self) -> MathResult<Box<dyn Layer<T>>> {
+        let layer: Box<dyn Layer<T>> = match self {
+            // weights are overwritten by the stored tensors, no need to draw random ones
+            LayerConfig::Dense { input_size, output_size } =>
+                Box::new(Dense::initialized(*input_size, *output_size, Initializer::Zeros, 0)),
+            LayerConfig::Activation { function } => Box::new(Activation::new(*function)),
+            LayerConfig::Softmax => Box::new(Softmax::new()),
+            LayerConfig::Dropout { rate } => Box::new(Dropout::new(*rate)?),
+            LayerConfig::BatchNorm { size, momentum, epsilon } => Box::new(BatchNorm::configured(*size, *momentum, *epsilon)?),
+            LayerConfig::LayerNorm { size, epsilon } => Box::new(LayerNorm::configured(*size, *epsilon)?),
+            LayerConfig::Conv2D { input_shape, output_channels, window } => Box::new(Conv2D::new(*input_shape, *output_channels, *window)?),
+            LayerConfig::MaxPool2D { input_shape, window } => Box::new(MaxPool2D::new(*input_shape, *window)?),
+            LayerConfig::AvgPool2D { input_shape, window } => Box::new(AvgPool2D::new(*input_shape, *window)?),
+            LayerConfig::GlobalAveragePool { input_shape } => Box::new(GlobalAveragePool::new(*input_shape)?),
+        };
+        Ok(layer)
+    }
+
+    /// dimensions of the tensors in `Layer::state` of the built layer,
+    /// lets the loader reject a manifest before allocating anything it describes
+    pub fn state_dimensions(&self) -> Vec<Dimensions> {
+        match self {
+            LayerConfig::Dense { input_size, output_size } =>
+                vec![Dimensions::new(*output_size, *input_size), Dimensions::new(*output_size, 1)],
+            LayerConfig::BatchNorm { size, .. } => vec![Dimensions::new(*size, 1); 4],
+            LayerConfig::LayerNorm { size, .. } => vec![Dimensions::new(*size, 1); 2],
+            LayerConfig::Conv2D { input_shape, output_channels, window } => {
+                let kernel_size = input_shape.channels
+                    .saturating_mul(window.kernel.0)
+                    .saturating_mul(window.kernel.1);
+                vec![Dimensions::new(*output_channels, kernel_size), Dimensions::new(*output_channels, 1)]
+            }
+            LayerConfig::Activation { .. }
+            | LayerConfig::Softmax
+            | LayerConfig::Dropout { .. }
+            | LayerConfig::MaxPool2D { .. }
+            | LayerConfig::AvgPool2D { .. }
+            | LayerConfig::GlobalAveragePool { .. } => Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct TensorShape {
+    rows: usize,
+    cols: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LayerEntry {
+    config: LayerConfig,
+    tensors: Vec<TensorShape>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    version: u32,
+    layers: Vec<LayerEntry>,
+}
+
+pub enum SerializationError {
+    Io(io::Error),
+    Format(String),
+    UnsupportedVersion(u32),
+    /// layer index, expected and stored tensors count
+    TensorsCount(usize, usize, usize),
+    /// layer index, expected and stored tensor dimensions
+    TensorShape(usize, Dimensions, Dimensions),
+    /// layer index and the error raised while building it from the config
+    Layer(usize, MathError),
+}
+
+impl SerializationError {
+    pub fn description(&self) -> String {
+        match self {
+            SerializationError::Io(error) =>
+                format!("IO error: {}", error),
+            SerializationError::Format(message) =>
+                format!("Incorrect model format: {}", message),
+            SerializationError::UnsupportedVersion(version) =>
+                format!("Unsupported model format version {}, expected {}", version, FORMAT_VERSION),
+            SerializationError::TensorsCount(layer, expected, found) =>
+                format!("Layer {} expects {} tensors but {} are stored", layer, expected, found),
+            SerializationError::TensorShape(layer, expected, found) =>
+                format!("Layer {} expects tensor with dimensions {:?} but {:?} is stored", layer, expected, found),
+            SerializationError::Layer(layer, error) =>
+                format!("Layer {} can't be built: {}", layer, error),
+        }
+    }
+}
+
+impl fmt::Debug for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for SerializationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SerializationError::Io(error) => Some(error),
+            SerializationError::Layer(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SerializationError {
+    fn from(error: io::Error) -> Self {
+        SerializationError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SerializationError {
+    fn from(error: serde_json::Error) -> Self {
+        SerializationError::Format(error.to_string())
+    }
+}
+
+pub type SerializationResult<T> = std::result::Result<T, SerializationError>;
+
+/// Writes layers into `directory` as JSON manifest and binary blob with little-endian f64 tensors,
+/// so models of any element type share the format
+pub fn save_layers<T: Scalar>(layers: &[Box<dyn Layer<T>>], directory: &Path) -> SerializationResult<()> {
+    fs::create_dir_all(directory)?;
+    let mut blob = TENSORS_MAGIC.to_vec();
+    let mut entries = Vec::with_capacity(layers.len());
+    for layer in layers {
+        let state = layer.state();
+        for tensor in state.iter() {
+            for i in 0..tensor.rows() {
+                for value in tensor[i].iter() {
+                    blob.extend_from_slice(&value.to_f64().to_le_bytes());
+                }
+            }
+        }
+        entries.push(LayerEntry {
+            config: layer.config(),
+            tensors: state.iter().map(|t| TensorShape { rows: t.rows(), cols: t.cols() }).collect(),
+        });
+    }
+    let manifest = Manifest {
+        version: FORMAT_VERSION,
+        layers: entries,
+    };
+    fs::write(directory.join(MANIFEST_FILE), serde_json::to_string_pretty(&manifest)?)?;
+    fs::write(directory.join(TENSORS_FILE), blob)?;
+    Ok(())
+}
+
+/// Recreates layers stored by `save_layers`
+pub fn load_layers<T: Scalar>(directory: &Path) -> SerializationResult<Vec<Box<dyn Layer<T>>>> {
+    let manifest: Manifest = serde_json::from_str(&fs::read_to_string(directory.join(MANIFEST_FILE))?)?;
+    if manifest.version != FORMAT_VERSION {
+        return Err(SerializationError::UnsupportedVersion(manifest.version));
+    }
+    let blob = fs::read(directory.join(TENSORS_FILE))?;
+    if !blob.starts_with(TENSORS_MAGIC) {
+        return Err(SerializationError::Format("tensors blob has no valid header".to_string()));
+    }
+    let expected_size = manifest.layers
+        .iter()
+        .flat_map(|entry| entry.tensors.iter())
+        .try_fold(TENSORS_MAGIC.len(), |size, shape| {
+            shape.rows
+                .checked_mul(shape.cols)
+                .and_then(|count| count.checked_mul(std::mem::size_of::<f64>()))
+                .and_then(|bytes| size.checked_add(bytes))
+        })
+        .ok_or_else(|| SerializationError::Format("tensor sizes overflow".to_string()))?;
+    if blob.len() != expected_size {
+        return Err(SerializationError::Format(format!("tensors blob size is {} bytes, expected {}", blob.len(), expected_size)));
+    }
+    let mut values = blob[TENSORS_MAGIC.len()..]
+        .chunks_exact(std::mem::size_of::<f64>())
+        .map(|bytes| f64::from_le_bytes(bytes.try_into().expect("chunk has size of f64")));
+
+    let mut layers = Vec::with_capacity(manifest.layers.len());
+    for (index, entry) in manifest.layers.iter().enumerate() {
+        let expected = entry.config.state_dimensions();
+        if expected.len() != entry.tensors.len() {
+            return Err(SerializationError::TensorsCount(index, expected.len(), entry.tensors.len()));
+        }
+        for (dimensions, shape) in expected.iter().zip(entry.tensors.iter()) {
+            let stored = Dimensions::new(shape.rows, shape.cols);
+            if *dimensions != stored {
+                return Err(SerializationError::TensorShape(index, *dimensions, stored));
+            }
+        }
+        let mut layer = entry.config.build().map_err(|error| SerializationError::Layer(index, error))?;
+        let mut state = layer.state_mut();
+        debug_assert_eq!(state.len(), expected.len(), "state of {:?} doesn't match its config", entry.config);
+        for (tensor, shape) in state.iter_mut().zip(entry.tensors.iter()) {
+            debug_assert_eq!(tensor.dimensions(), Dimensions::new(shape.rows, shape.cols), "state of {:?} doesn't match its config", entry.config);
+            let content: Vec<T> = values.by_ref().take(shape.rows * shape.cols).map(T::from_f64).collect();
+            **tensor = Matrix::new(shape.rows, shape.cols, |i, j| content[i * shape.cols + j]);
+        }
+        layers.push(layer);
+    }
+    Ok(layers)
+}
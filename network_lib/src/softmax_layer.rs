@@ -0,0 +1,65 @@
+use matrix_lib::{
+    errors::*,
+    matrix::*,
+    matrix_functions::softmax,
+    scalar::Scalar,
+};
+use super::{
+    layer::*,
+    serialization::LayerConfig,
+};
+
+/// Column-wise softmax, each column of the batch is turned into a distribution over classes.
+/// Since the outputs are coupled, backward applies the full Jacobian `diag(y) - y * y^T`.
+/// When training with cross-entropy it's cheaper and more stable to end the network with logits
+/// and use `SoftmaxCrossEntropy` loss instead
+pub struct Softmax<T: Scalar = f64> {
+    output: Matrix<T>,
+}
+
+impl<T: Scalar> Softmax<T> {
+    pub fn new() -> Self {
+        Self {
+            output: Matrix::empty(),
+        }
+    }
+}
+
+impl<T: Scalar> Default for Softmax<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Scalar> Layer<T> for Softmax<T> {
+    fn eval(&self, input: &Matrix<T>) -> MathResult<Matrix<T>> {
+        Ok(softmax(input))
+    }
+
+    fn forward(&mut self, input: Matrix<T>) -> MathResult<Matrix<T>> {
+        self.output = softmax(&input);
+        Ok(self.output.clone())
+    }
+
+    /// for each column computes `J^T * g = y * (g - <g, y>)`
+    fn backward(&mut self, output_gradient: &Matrix<T>) -> MathResult<Matrix<T>> {
+        let output = &self.output;
+        if !output.is_same_size(output_gradient) {
+            return Err(MathError::IncorrectMatricesDimensions("softmax backward".to_string(), output.dimensions(), output_gradient.dimensions()));
+        }
+        let mut gradient = Matrix::zero(output.rows(), output.cols());
+        for j in 0..output.cols() {
+            let dot: T = (0..output.rows())
+                .map(|i| output_gradient[i][j] * output[i][j])
+                .sum();
+            for i in 0..output.rows() {
+                gradient[i][j] = output[i][j] * (output_gradient[i][j] - dot);
+            }
+        }
+        Ok(gradient)
+    }
+
+    fn config(&self) -> LayerConfig {
+        LayerConfig::Softmax
+    }
+}
@@ -0,0 +1,90 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+mod common;
+
+use network_lib::{
+    activation_layer::*,
+    layer::Layer,
+};
+
+use matrix_lib::{errors::MathResult, matrix::Matrix};
+
+use common::*;
+
+fn all_activations() -> Vec<Activation> {
+    vec![
+        Activation::linear(),
+        Activation::tanh(),
+        Activation::sigmoid(),
+        Activation::relu(),
+        Activation::leaky_relu(0.1),
+        Activation::elu(0.7),
+        Activation::selu(),
+        Activation::gelu(),
+        Activation::swish(),
+        Activation::softplus(),
+    ]
+}
+
+#[test]
+fn activation_backward_matches_numeric_derivative() -> MathResult<()> {
+    let input = Matrix::vector(&[-3.0, -1.2, -0.3, 0.4, 1.1, 2.5])?;
+    let ones = Matrix::new(input.rows(), 1, |_, _| 1.0);
+    for mut layer in all_activations() {
+        layer.forward(input.clone())?;
+        let gradient = layer.backward(&ones)?;
+        let name = format!("{:?}", layer.function());
+        let sum = |input: &Matrix| -> MathResult<f64> {
+            let output = layer.eval(input)?;
+            Ok((0..output.rows()).map(|i| output[i][0]).sum())
+        };
+        check_numerical_gradient(&name, sum, &input, &gradient)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn activation_known_values() {
+    assert_eq!(ActivationFunction::Relu.value(-2.0), 0.0);
+    assert_eq!(ActivationFunction::Relu.value(2.0), 2.0);
+    assert_eq!(ActivationFunction::LeakyRelu(0.1).value(-2.0), -0.2);
+    assert!((ActivationFunction::Elu(1.0).value::<f64>(-1000.0) + 1.0).abs() < 1e-12, "ELU should saturate at -alpha");
+    assert!((ActivationFunction::Selu.value::<f64>(1.0) - 1.050_700_987_355_480_5).abs() < 1e-12);
+    assert!((ActivationFunction::Gelu.value::<f64>(1.0) - 0.841_192).abs() < 1e-6);
+    assert!((ActivationFunction::Swish.value::<f64>(0.0)).abs() < 1e-12);
+    assert!((ActivationFunction::Softplus.value(0.0) - 2f64.ln()).abs() < 1e-12);
+    assert!(ActivationFunction::Softplus.value::<f64>(1000.0).is_finite(), "Softplus overflow");
+}
+
+#[test]
+fn activation_batch_shape() -> MathResult<()> {
+    let input = Matrix::from_vector(&[
+        vec![-1.0, 0.5, 2.0],
+        vec![0.0, -0.5, 1.0],
+    ])?;
+    let mut layer = Activation::relu();
+    let output = layer.forward(input.clone())?;
+    assert_eq!(output.dimensions(), input.dimensions());
+    let gradient = layer.backward(&Matrix::new(2, 3, |_, _| 1.0))?;
+    let expected = Matrix::from_vector(&[
+        vec![0.0, 1.0, 1.0],
+        vec![0.0, 0.0, 1.0],
+    ])?;
+    assert_eq!(gradient, expected, "ReLU batch gradient computed incorrectly");
+    Ok(())
+}
+
+#[test]
+fn activation_single_precision() -> MathResult<()> {
+    let input = Matrix::new(3, 4, |i, j| (i as f64 - 1.0) * 1.5 + j as f64 * 0.3);
+    let gradient = Matrix::new(3, 4, |i, j| ((i + j) % 3) as f64 - 1.0);
+    for mut activation in all_activations() {
+        let mut single: Activation<f32> = Activation::new(activation.function());
+        let expected = activation.forward(input.clone())?;
+        assert!(single.forward(input.cast())?.cast().approx_eq(&expected, 1e-5), "{:?} differs in f32", activation.function());
+        let expected = activation.backward(&gradient)?;
+        assert!(single.backward(&gradient.cast())?.cast().approx_eq(&expected, 1e-5), "{:?} derivative differs in f32", activation.function());
+    }
+    Ok(())
+}
@@ -0,0 +1,95 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+mod common;
+
+use std::fs;
+
+use network_lib::{
+    activation_layer::Activation, batch_norm_layer::BatchNorm, data_source::TrainDataSource,
+    dense_layer::Dense, layer::*, loss::Mse, network::FeedforwardNetwork, optimizer::Adam, serialization::LayerConfig,
+};
+
+use matrix_lib::{errors::*, matrix::Matrix, matrix_functions::*, matrix_reductions::Axis};
+
+use common::*;
+
+/// sum of output elements weighted by `weights`, its gradient by the output is `weights`
+fn weighted_output(input: &Matrix, weights: &Matrix) -> MathResult<f64> {
+    let mut layer = BatchNorm::new(input.rows());
+    layer.forward(input.clone())?.dot(weights)
+}
+
+fn normalized(input: &Matrix) -> MathResult<Matrix> {
+    BatchNorm::new(input.rows()).forward(input.clone())
+}
+
+#[test]
+fn batch_norm_training_normalizes() -> MathResult<()> {
+    let input = sample_input(3, 5);
+    let mut layer = BatchNorm::new(3);
+    let output = layer.forward(input.clone())?;
+    assert!(output.mean_axis(Axis::Columns)?.approx_eq(&Matrix::zero(3, 1), 1e-12), "Output features should be centered");
+    assert!(output.variance_axis(Axis::Columns)?.approx_eq(&Matrix::new(3, 1, |_, _| 1.0), 1e-4), "Output features should have unit variance");
+
+    let expected_mean = input.mean_axis(Axis::Columns)?.mul(0.1);
+    assert!(layer.running_mean().approx_eq(&expected_mean, 1e-12), "Running mean updated incorrectly");
+    let expected_variance = input.variance_axis(Axis::Columns)?.mul(0.1).map(|x| x + 0.9);
+    assert!(layer.running_variance().approx_eq(&expected_variance, 1e-12), "Running variance updated incorrectly");
+
+    let eval = layer.eval(&input)?;
+    layer.set_mode(Mode::Inference);
+    assert_eq!(layer.forward(input.clone())?, eval, "Inference mode should use running statistics");
+    assert!(layer.running_mean().approx_eq(&expected_mean, 1e-12), "Inference shouldn't update running statistics");
+    assert!(layer.forward(Matrix::zero(2, 5)).is_err());
+    Ok(())
+}
+
+#[test]
+fn batch_norm_backward_matches_numerical_gradient() -> MathResult<()> {
+    let input = sample_input(3, 5);
+    let weights = Matrix::new(3, 5, |i, j| ((i + 2 * j) % 4) as f64 - 1.5);
+    let mut layer = BatchNorm::new(3);
+    layer.forward(input.clone())?;
+    let gradient = layer.backward(&weights)?;
+    check_numerical_gradient("Input", |input| weighted_output(input, &weights), &input, &gradient)?;
+
+    let parameters = layer.parameters();
+    assert!(parameters[0].gradient.approx_eq(&mul(&weights, &normalized(&input)?)?.sum_axis(Axis::Columns), 1e-12), "Gamma gradient implemented incorrectly");
+    assert!(parameters[1].gradient.approx_eq(&weights.sum_axis(Axis::Columns), 1e-12), "Beta gradient implemented incorrectly");
+    Ok(())
+}
+
+#[test]
+fn batch_norm_invalid_parameters() {
+    for (momentum, epsilon) in [(1.5, 1e-5), (-0.1, 1e-5), (f64::NAN, 1e-5), (0.9, 0.0), (0.9, -1e-5), (0.9, f64::NAN)] {
+        let result: MathResult<BatchNorm> = BatchNorm::configured(3, momentum, epsilon);
+        assert!(matches!(result, Err(MathError::InvalidParameter(_, _))), "Momentum {} and epsilon {} weren't rejected", momentum, epsilon);
+        let config = LayerConfig::BatchNorm { size: 3, momentum, epsilon };
+        assert!(config.build::<f64>().is_err(), "Config with momentum {} and epsilon {} wasn't rejected", momentum, epsilon);
+    }
+}
+
+#[test]
+fn batch_norm_network_round_trip() -> MathResult<()> {
+    let mut network: FeedforwardNetwork = FeedforwardNetwork::new(vec![
+        Box::new(Dense::new(2, 8)),
+        Box::new(BatchNorm::new(8)),
+        Box::new(Activation::tanh()),
+        Box::new(Dense::new(8, 1)),
+    ]);
+    let mut data = TrainDataSource::new();
+    for (x, y) in [([0.0, 0.0], 0.0), ([1.0, 0.0], 1.0), ([0.0, 1.0], 1.0), ([1.0, 1.0], 0.0)] {
+        data.push(Matrix::vector(&x)?, Matrix::vector(&[y])?);
+    }
+    let error = network.train(1_000, &Mse, &mut Adam::new(0.02, 0.9, 0.999), 4, &data)?;
+    assert!(error < 0.05, "Network with batch norm wasn't trained, error = {}", error);
+
+    let dir = std::env::temp_dir().join(format!("network_lib_batch_norm_{}", std::process::id()));
+    network.save(&dir).expect("Network should be saved");
+    let loaded = FeedforwardNetwork::<f64>::load(&dir).expect("Network should be loaded");
+    let input = data.batches(4)?[0].input.clone();
+    assert_eq!(loaded.eval(&input)?, network.eval(&input)?, "Running statistics weren't restored");
+    _ = fs::remove_dir_all(&dir);
+    Ok(())
+}
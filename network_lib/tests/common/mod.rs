@@ -0,0 +1,32 @@
+// every test crate compiles its own copy, not all of them use each helper
+#![allow(dead_code)]
+
+use matrix_lib::{errors::MathResult, matrix::Matrix};
+
+/// deterministic input with distinct values in every window of the test images
+pub fn sample_input(rows: usize, cols: usize) -> Matrix {
+    Matrix::new(rows, cols, |i, j| ((i * 7 + j * 11) % 17) as f64 * 0.25 - 2.0)
+}
+
+/// compares `analytical` gradient of scalar function `f` at `input` with central finite differences
+pub fn check_numerical_gradient<F>(name: &str, f: F, input: &Matrix, analytical: &Matrix) -> MathResult<()>
+where
+    F: Fn(&Matrix) -> MathResult<f64>,
+{
+    assert_eq!(analytical.dimensions(), input.dimensions(), "{} gradient has incorrect dimensions", name);
+    let h = 1e-6;
+    for i in 0..input.rows() {
+        for j in 0..input.cols() {
+            let mut plus = input.clone();
+            plus[i][j] += h;
+            let mut minus = input.clone();
+            minus[i][j] -= h;
+            let numerical = (f(&plus)? - f(&minus)?) / (2.0 * h);
+            assert!(
+                (analytical[i][j] - numerical).abs() < 1e-6,
+                "{} gradient at {}:{} is {}, numerical value is {}", name, i, j, analytical[i][j], numerical
+            );
+        }
+    }
+    Ok(())
+}
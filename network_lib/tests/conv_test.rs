@@ -0,0 +1,131 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+mod common;
+
+use std::fs;
+
+use network_lib::{
+    activation_layer::Activation, conv_layer::Conv2D, dense_layer::Dense, image::*, initializer::Initializer,
+    layer::*, network::FeedforwardNetwork, serialization::LayerConfig,
+};
+
+use matrix_lib::{errors::*, matrix::Matrix};
+
+use common::*;
+
+const INPUT: ImageShape = ImageShape { channels: 2, height: 5, width: 6 };
+
+fn window() -> Window {
+    Window::new(3, 2).with_stride(2).with_padding(1).with_dilation(2)
+}
+
+fn layer(window: Window) -> Conv2D {
+    let mut layer = Conv2D::initialized(INPUT, 3, window, Initializer::XavierUniform, 7).unwrap();
+    *layer.state_mut()[1] = Matrix::vector(&[0.1, -0.2, 0.3]).unwrap();
+    layer
+}
+
+/// direct convolution of every output element
+fn naive_convolution(layer: &Conv2D, window: Window, input: &Matrix) -> Matrix {
+    let output = layer.output_shape();
+    let (weight, bias) = (layer.state()[0], layer.state()[1]);
+    let (kernel_height, kernel_width) = window.kernel;
+    Matrix::new(output.size(), input.cols(), |i, b| {
+        let (o, oy, ox) = (i / (output.height * output.width), i / output.width % output.height, i % output.width);
+        let mut sum = bias[o][0];
+        for c in 0..INPUT.channels {
+            for ki in 0..kernel_height {
+                for kj in 0..kernel_width {
+                    let y = (oy * window.stride.0 + ki * window.dilation.0) as isize - window.padding.0 as isize;
+                    let x = (ox * window.stride.1 + kj * window.dilation.1) as isize - window.padding.1 as isize;
+                    if y >= 0 && x >= 0 && (y as usize) < INPUT.height && (x as usize) < INPUT.width {
+                        let source = (c * INPUT.height + y as usize) * INPUT.width + x as usize;
+                        sum += weight[o][(c * kernel_height + ki) * kernel_width + kj] * input[source][b];
+                    }
+                }
+            }
+        }
+        sum
+    })
+}
+
+#[test]
+fn window_output_size() {
+    assert_eq!(Window::square(3).output_size(5, 6), Some((3, 4)));
+    assert_eq!(Window::square(3).with_padding(1).output_size(5, 6), Some((5, 6)));
+    assert_eq!(Window::square(3).with_stride(2).output_size(5, 6), Some((2, 2)));
+    assert_eq!(Window::square(3).with_dilation(2).output_size(5, 6), Some((1, 2)));
+    assert_eq!(Window::square(7).output_size(5, 6), None);
+    assert_eq!(window().output_shape(INPUT), Some(ImageShape::new(2, 2, 3)));
+}
+
+#[test]
+fn conv_matches_direct_convolution() -> MathResult<()> {
+    let input = sample_input(INPUT.size(), 2);
+    for window in [Window::square(3), Window::square(2).with_padding(1), window()] {
+        let mut layer = layer(window);
+        let expected = naive_convolution(&layer, window, &input);
+        assert!(layer.eval(&input)?.approx_eq(&expected, 1e-12), "Convolution with {:?} implemented incorrectly", window);
+        assert!(layer.forward(input.clone())?.approx_eq(&expected, 1e-12));
+    }
+    Ok(())
+}
+
+#[test]
+fn conv_backward_matches_numerical_gradient() -> MathResult<()> {
+    let input = sample_input(INPUT.size(), 2);
+    let mut trained = layer(window());
+    let weights = Matrix::new(trained.output_shape().size(), 2, |i, j| ((i + 3 * j) % 5) as f64 - 2.0);
+    trained.forward(input.clone())?;
+    let gradient = trained.backward(&weights)?;
+    assert!(trained.backward(&Matrix::zero(3, 2)).is_err());
+
+    let reference = layer(window());
+    check_numerical_gradient("Input", |input| reference.eval(input)?.dot(&weights), &input, &gradient)?;
+
+    for (k, parameter) in trained.parameters().iter().enumerate() {
+        let value = |state: &Matrix| -> MathResult<f64> {
+            let mut perturbed = layer(window());
+            *perturbed.state_mut()[k] = state.clone();
+            perturbed.eval(&input)?.dot(&weights)
+        };
+        check_numerical_gradient(&format!("Parameter {}", k), value, parameter.value, parameter.gradient)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn conv_rejects_incorrect_input() {
+    let mut layer = layer(window());
+    assert!(layer.eval(&Matrix::zero(INPUT.size() - 1, 2)).is_err());
+    assert!(layer.forward(Matrix::zero(INPUT.size() + 1, 1)).is_err());
+}
+
+#[test]
+fn conv_rejects_incorrect_window() {
+    for window in [Window::square(7), Window::square(0), Window::square(3).with_stride(0), Window::square(3).with_dilation(usize::MAX)] {
+        let result: MathResult<Conv2D> = Conv2D::new(INPUT, 2, window);
+        assert!(matches!(result, Err(MathError::IncorrectMatricesDimensions(..))), "Window {:?} wasn't rejected", window);
+        let config = LayerConfig::Conv2D { input_shape: INPUT, output_channels: 2, window };
+        assert!(config.build::<f64>().is_err(), "Config with window {:?} wasn't rejected", window);
+    }
+}
+
+#[test]
+fn conv_network_round_trip() -> MathResult<()> {
+    let conv = Conv2D::new(INPUT, 4, window())?;
+    let output = conv.output_shape().size();
+    let network: FeedforwardNetwork = FeedforwardNetwork::new(vec![
+        Box::new(conv),
+        Box::new(Activation::relu()),
+        Box::new(Dense::new(output, 2)),
+    ]);
+    let dir = std::env::temp_dir().join(format!("network_lib_conv_{}", std::process::id()));
+    network.save(&dir).expect("Network should be saved");
+    let loaded = FeedforwardNetwork::<f64>::load(&dir).expect("Network should be loaded");
+    let input = sample_input(INPUT.size(), 2);
+    assert_eq!(loaded.eval(&input)?, network.eval(&input)?, "Convolution weights weren't restored");
+    _ = fs::remove_dir_all(&dir);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+use network_lib::{
+    activation_layer::Activation, data_source::TrainDataSource, dense_layer::Dense,
+    dropout_layer::Dropout, layer::*, loss::Mse, network::FeedforwardNetwork, optimizer::Adam,
+};
+
+use matrix_lib::{errors::*, matrix::Matrix};
+
+#[test]
+fn dropout_training_mask() -> MathResult<()> {
+    let input = Matrix::new(50, 40, |i, j| 1.0 + (i + j) as f64);
+    let mut dropout = Dropout::seeded(0.25, 3)?;
+    let output = dropout.forward(input.clone())?;
+    let mut dropped = 0;
+    for i in 0..input.rows() {
+        for j in 0..input.cols() {
+            if output[i][j] == 0.0 {
+                dropped += 1;
+            } else {
+                assert!((output[i][j] - input[i][j] / 0.75).abs() < 1e-12, "Kept elements should be scaled");
+            }
+        }
+    }
+    let rate = dropped as f64 / 2000.0;
+    assert!((rate - 0.25).abs() < 0.05, "Unexpected share of dropped elements {}", rate);
+
+    let gradient = dropout.backward(&Matrix::new(50, 40, |_, _| 1.0))?;
+    for i in 0..input.rows() {
+        for j in 0..input.cols() {
+            assert_eq!(gradient[i][j] == 0.0, output[i][j] == 0.0, "Gradient should be masked like the output");
+        }
+    }
+
+    let mut same_seed = Dropout::seeded(0.25, 3)?;
+    assert_eq!(same_seed.forward(input.clone())?, output, "Seeded dropout should be reproducible");
+    Ok(())
+}
+
+#[test]
+fn dropout_inference_mode() -> MathResult<()> {
+    let input = Matrix::random(6, 5);
+    let mut dropout = Dropout::new(0.5)?;
+    assert_eq!(dropout.eval(&input)?, input, "Dropout should be identity in eval");
+    dropout.set_mode(Mode::Inference);
+    assert_eq!(dropout.forward(input.clone())?, input, "Dropout should be identity in inference mode");
+    assert_eq!(dropout.backward(&input)?, input);
+    Ok(())
+}
+
+#[test]
+fn dropout_incorrect_rate() {
+    for rate in [1.0, -0.1, f64::NAN, f64::INFINITY] {
+        let result: MathResult<Dropout> = Dropout::new(rate);
+        assert!(matches!(result, Err(MathError::InvalidParameter(_, _))), "Rate {} wasn't rejected", rate);
+    }
+}
+
+#[test]
+fn network_mode_switch() -> MathResult<()> {
+    let mut network: FeedforwardNetwork = FeedforwardNetwork::new(vec![
+        Box::new(Dense::new(2, 16)),
+        Box::new(Activation::tanh()),
+        Box::new(Dropout::seeded(0.1, 5)?),
+        Box::new(Dense::new(16, 1)),
+    ]);
+    let mut data = TrainDataSource::new();
+    data.push(Matrix::vector(&[0.0, 1.0])?, Matrix::vector(&[1.0])?);
+    data.push(Matrix::vector(&[1.0, 0.0])?, Matrix::vector(&[-1.0])?);
+
+    network.set_mode(Mode::Inference);
+    network.train(500, &Mse, &mut Adam::new(0.01, 0.9, 0.999), 2, &data)?;
+    assert_eq!(network.mode(), Mode::Inference, "Train should restore the previous mode");
+
+    let input = Matrix::vector(&[0.0, 1.0])?;
+    assert!((network.eval(&input)?[0][0] - 1.0).abs() < 0.2, "Network with dropout wasn't trained");
+
+    let batch = Matrix::new(2, 8, |i, j| ((i + j) % 3) as f64 - 1.0);
+    assert_eq!(network.forward(batch.clone())?, network.eval(&batch)?, "Inference forward should match eval");
+    network.set_mode(Mode::Training);
+    assert_eq!(network.mode(), Mode::Training);
+    assert_ne!(network.forward(batch.clone())?, network.eval(&batch)?, "Training forward should apply dropout");
+    Ok(())
+}
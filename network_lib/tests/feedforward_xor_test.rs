@@ -3,28 +3,28 @@ extern crate network_lib;
 
 use network_lib::{
     activation_layer::Activation, data_source::TrainDataSource, dense_layer::Dense,
-    network::FeedforwardNetwork,
+    initializer::Initializer, loss::*, network::FeedforwardNetwork, optimizer::*,
 };
 
-use matrix_lib::{errors::MathResult, matrix::Matrix, matrix_functions::sub};
+use matrix_lib::{errors::*, matrix::Matrix, matrix_functions::sub};
 
 fn xor_data_source() -> MathResult<TrainDataSource> {
     let mut data = TrainDataSource::new();
     data.push(
-        Matrix::vector(&vec![0.0, 0.0])?,
-        Matrix::vector(&vec![0.0])?,
+        Matrix::vector(&[0.0, 0.0])?,
+        Matrix::vector(&[0.0])?,
     );
     data.push(
-        Matrix::vector(&vec![1.0, 0.0])?,
-        Matrix::vector(&vec![1.0])?,
+        Matrix::vector(&[1.0, 0.0])?,
+        Matrix::vector(&[1.0])?,
     );
     data.push(
-        Matrix::vector(&vec![0.0, 1.0])?,
-        Matrix::vector(&vec![1.0])?,
+        Matrix::vector(&[0.0, 1.0])?,
+        Matrix::vector(&[1.0])?,
     );
     data.push(
-        Matrix::vector(&vec![1.0, 1.0])?,
-        Matrix::vector(&vec![0.0])?,
+        Matrix::vector(&[1.0, 1.0])?,
+        Matrix::vector(&[0.0])?,
     );
     Ok(data)
 }
@@ -33,19 +33,19 @@ fn xor_data_source() -> MathResult<TrainDataSource> {
 fn network_xor_training() -> MathResult<()> {
     // xor training
     let mut network = FeedforwardNetwork::new(vec![
-        Box::new(Dense::new(2, 3)),
+        Box::new(Dense::initialized(2, 3, Initializer::XavierUniform, 7)),
         Box::new(Activation::tanh()),
-        Box::new(Dense::new(3, 1)),
+        Box::new(Dense::initialized(3, 1, Initializer::XavierUniform, 11)),
         Box::new(Activation::tanh()),
     ]);
     let data_source = xor_data_source()?;
-    _ = network.train(30_000, 0.05, &data_source);
+    _ = network.train(30_000, &Mse, &mut Sgd::new(0.05), 1, &data_source);
     let eps = 1e-1;
     for item in data_source.content() {
         let input = &item.input;
         let eval = network.eval(input)?;
         assert!(
-            sub(&eval, &item.output)?.mean() < eps,
+            sub(&eval, &item.output)?.max_abs() < eps,
             "Wrong evaluated value for case {:?}, value = {:?}, expected = {:?}",
             input,
             eval,
@@ -54,3 +54,79 @@ fn network_xor_training() -> MathResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn network_xor_batch_training() -> MathResult<()> {
+    let mut network = FeedforwardNetwork::new(vec![
+        Box::new(Dense::initialized(2, 3, Initializer::XavierUniform, 7)),
+        Box::new(Activation::tanh()),
+        Box::new(Dense::initialized(3, 1, Initializer::XavierUniform, 11)),
+        Box::new(Activation::tanh()),
+    ]);
+    let data_source = xor_data_source()?;
+    _ = network.train(30_000, &Mse, &mut Sgd::new(0.2), 4, &data_source);
+    let eps = 1e-1;
+    let batch = &data_source.batches(4)?[0];
+    let eval = network.eval(&batch.input)?;
+    assert_eq!(eval.dimensions(), batch.output.dimensions(), "Batch output has unexpected dimensions");
+    assert!(
+        sub(&eval, &batch.output)?.max_abs() < eps,
+        "Wrong evaluated values for batch, value = {:?}, expected = {:?}",
+        eval,
+        batch.output
+    );
+    Ok(())
+}
+
+#[test]
+fn network_xor_adam_training() -> MathResult<()> {
+    let mut network = FeedforwardNetwork::new(vec![
+        Box::new(Dense::initialized(2, 3, Initializer::XavierUniform, 7)),
+        Box::new(Activation::tanh()),
+        Box::new(Dense::initialized(3, 1, Initializer::XavierUniform, 11)),
+        Box::new(Activation::tanh()),
+    ]);
+    let data_source = xor_data_source()?;
+    let error = network.train(3_000, &Mse, &mut Adam::new(0.01, 0.9, 0.999), 4, &data_source)?;
+    assert!(error < 1e-2, "Network wasn't trained with Adam, error = {}", error);
+    Ok(())
+}
+
+#[test]
+fn network_xor_single_precision_training() -> MathResult<()> {
+    let mut network: FeedforwardNetwork<f32> = FeedforwardNetwork::new(vec![
+        Box::new(Dense::initialized(2, 8, Initializer::XavierUniform, 7)),
+        Box::new(Activation::tanh()),
+        Box::new(Dense::initialized(8, 1, Initializer::XavierUniform, 11)),
+        Box::new(Activation::tanh()),
+    ]);
+    let mut data_source = TrainDataSource::new();
+    for item in xor_data_source()?.content() {
+        data_source.push(item.input.cast(), item.output.cast());
+    }
+    let error = network.train(3_000, &Mse, &mut Adam::new(0.05, 0.9, 0.999), 4, &data_source)?;
+    assert!(error < 1e-2, "Single precision network wasn't trained, error = {}", error);
+    Ok(())
+}
+
+#[test]
+fn data_source_batches() -> MathResult<()> {
+    let data_source = xor_data_source()?;
+    let batches = data_source.batches(3)?;
+    assert_eq!(batches.len(), 2, "Unexpected number of batches");
+    assert_eq!(batches[0].input, Matrix::from_vector(&[
+        vec![0.0, 1.0, 0.0],
+        vec![0.0, 0.0, 1.0],
+    ])?);
+    assert_eq!(batches[0].output, Matrix::from_vector(&[vec![0.0, 1.0, 1.0]])?);
+    assert_eq!(batches[1].input, Matrix::vector(&[1.0, 1.0])?);
+
+    let mut mismatched = xor_data_source()?;
+    mismatched.push(Matrix::vector(&[1.0, 1.0, 1.0])?, Matrix::vector(&[0.0])?);
+    assert!(mismatched.batches(5).is_err(), "Inputs with different sizes can't be stacked");
+    assert!(
+        matches!(data_source.batches(0), Err(MathError::InvalidParameter(name, _)) if name == "batch size"),
+        "Zero batch size should be rejected"
+    );
+    Ok(())
+}
@@ -0,0 +1,115 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+use network_lib::{
+    dense_layer::Dense, initializer::Initializer, layer::Layer,
+};
+
+use matrix_lib::{errors::MathResult, matrix::Matrix};
+
+const ALL_INITIALIZERS: [Initializer; 10] = [
+    Initializer::Zeros,
+    Initializer::Constant(0.5),
+    Initializer::Uniform,
+    Initializer::XavierUniform,
+    Initializer::XavierNormal,
+    Initializer::HeUniform,
+    Initializer::HeNormal,
+    Initializer::LecunUniform,
+    Initializer::LecunNormal,
+    Initializer::Orthogonal(1.0),
+];
+
+fn assert_bit_identical(a: &Matrix, b: &Matrix, message: &str) {
+    assert_eq!(a.dimensions(), b.dimensions(), "{}", message);
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            assert_eq!(a[i][j].to_bits(), b[i][j].to_bits(), "{}", message);
+        }
+    }
+}
+
+fn std_deviation(m: &Matrix) -> f64 {
+    let mean = m.mean();
+    m.map(|x| (x - mean).powi(2)).mean().sqrt()
+}
+
+#[test]
+fn initializer_same_seed_is_reproducible() {
+    for initializer in ALL_INITIALIZERS {
+        let a = initializer.seeded_matrix(7, 5, 42);
+        let b = initializer.seeded_matrix(7, 5, 42);
+        assert_bit_identical(&a, &b, &format!("{:?} isn't reproducible", initializer));
+    }
+    let a = Initializer::HeNormal.seeded_matrix(7, 5, 1);
+    let b = Initializer::HeNormal.seeded_matrix(7, 5, 2);
+    assert!(a != b, "Different seeds should produce different matrices");
+}
+
+#[test]
+fn initializer_dense_is_reproducible() -> MathResult<()> {
+    let input = Matrix::vector(&[0.1, 0.2, 0.3, 0.4])?;
+    for initializer in ALL_INITIALIZERS {
+        let mut a = Dense::initialized(4, 3, initializer, 123);
+        let mut b = Dense::initialized(4, 3, initializer, 123);
+        let message = format!("Dense layers with {:?} and the same seed differ", initializer);
+        for (x, y) in a.state().into_iter().zip(b.state()) {
+            assert_bit_identical(x, y, &message);
+        }
+        assert_bit_identical(&a.forward(input.clone())?, &b.forward(input.clone())?, &message);
+    }
+    Ok(())
+}
+
+#[test]
+fn initializer_uniform_limits() {
+    let (rows, cols) = (40, 60);
+    let m = Initializer::XavierUniform.seeded_matrix(rows, cols, 3);
+    let limit = (6.0 / (rows + cols) as f64).sqrt();
+    for i in 0..rows {
+        for j in 0..cols {
+            assert!(m[i][j].abs() <= limit, "Value {} is out of Xavier limit {}", m[i][j], limit);
+        }
+    }
+    assert!(m.mean().abs() < 0.05, "Weights should be centered around zero");
+}
+
+#[test]
+fn initializer_normal_deviation() {
+    let (rows, cols) = (200, 100);
+    let m = Initializer::HeNormal.seeded_matrix(rows, cols, 5);
+    let expected = (2.0 / cols as f64).sqrt();
+    let actual = std_deviation(&m);
+    assert!((actual - expected).abs() < 0.05 * expected, "He normal std is {}, expected {}", actual, expected);
+
+    let m = Initializer::LecunNormal.seeded_matrix(rows, cols, 5);
+    let expected = (1.0 / cols as f64).sqrt();
+    let actual = std_deviation(&m);
+    assert!((actual - expected).abs() < 0.05 * expected, "LeCun normal std is {}, expected {}", actual, expected);
+}
+
+#[test]
+fn initializer_constants() {
+    assert_eq!(Initializer::Zeros.seeded_matrix(2, 3, 0), Matrix::zero(2, 3));
+    assert_eq!(Initializer::Constant(0.5).seeded_matrix(2, 3, 0), Matrix::new(2, 3, |_, _| 0.5));
+}
+
+#[test]
+fn initializer_orthogonal() -> MathResult<()> {
+    for (rows, cols) in [(6, 4), (4, 6), (5, 5)] {
+        let m = Initializer::Orthogonal(1.0).seeded_matrix(rows, cols, 9);
+        let gram = if rows >= cols {
+            m.transpose().product(&m)?
+        } else {
+            m.product(&m.transpose())?
+        };
+        let identity = Matrix::identity(rows.min(cols));
+        let error = gram.sub(&identity)?.powi(2).mean();
+        assert!(error < 1e-20, "Matrix {}x{} isn't orthogonal: {:?}", rows, cols, gram);
+    }
+    for (rows, cols) in [(0, 3), (3, 0), (1, 1), (1, 8), (64, 64)] {
+        let m = Initializer::Orthogonal(1.0).seeded_matrix(rows, cols, 3);
+        assert!(m.is_finite(), "Matrix {}x{} has NaN weights", rows, cols);
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+mod common;
+
+use network_lib::{layer::*, layer_norm_layer::LayerNorm, serialization::LayerConfig};
+
+use matrix_lib::{errors::*, matrix::Matrix, matrix_reductions::Axis};
+
+use common::*;
+
+/// layer with non-trivial scale and shift, the same for every call
+fn layer() -> LayerNorm {
+    let mut layer = LayerNorm::new(4);
+    let mut state = layer.state_mut();
+    *state[0] = Matrix::vector(&[1.0, 0.5, -2.0, 1.5]).unwrap();
+    *state[1] = Matrix::vector(&[0.1, 0.2, 0.3, 0.4]).unwrap();
+    layer
+}
+
+#[test]
+fn layer_norm_normalizes_samples() -> MathResult<()> {
+    let input = sample_input(4, 3);
+    let output = LayerNorm::new(4).forward(input.clone())?;
+    assert!(output.mean_axis(Axis::Rows)?.approx_eq(&Matrix::zero(1, 3), 1e-12), "Samples should be centered");
+    assert!(output.variance_axis(Axis::Rows)?.approx_eq(&Matrix::new(1, 3, |_, _| 1.0), 1e-4), "Samples should have unit variance");
+
+    let layer = layer();
+    let batch = layer.eval(&input)?;
+    for j in 0..input.cols() {
+        let single = layer.eval(&Matrix::new(4, 1, |i, _| input[i][j]))?;
+        for i in 0..input.rows() {
+            assert!((single[i][0] - batch[i][j]).abs() < 1e-12, "Samples should be normalized independently");
+        }
+    }
+    assert!(layer.eval(&Matrix::zero(3, 3)).is_err());
+    Ok(())
+}
+
+#[test]
+fn layer_norm_backward_matches_numerical_gradient() -> MathResult<()> {
+    let input = sample_input(4, 3);
+    let weights = Matrix::new(4, 3, |i, j| ((i + 2 * j) % 5) as f64 - 2.0);
+    let mut trained = layer();
+    trained.forward(input.clone())?;
+    let gradient = trained.backward(&weights)?;
+
+    check_numerical_gradient("Input", |input| layer().eval(input)?.dot(&weights), &input, &gradient)?;
+
+    let normalized = LayerNorm::new(4).forward(input.clone())?;
+    let parameters = trained.parameters();
+    let expected = Matrix::new(4, 1, |i, _| (0..3).map(|j| weights[i][j] * normalized[i][j]).sum());
+    assert!(parameters[0].gradient.approx_eq(&expected, 1e-12), "Gamma gradient implemented incorrectly");
+    assert!(parameters[1].gradient.approx_eq(&weights.sum_axis(Axis::Columns), 1e-12), "Beta gradient implemented incorrectly");
+    Ok(())
+}
+
+#[test]
+fn layer_norm_invalid_epsilon() {
+    for epsilon in [0.0, -1e-5, f64::NAN, f64::INFINITY] {
+        let result: MathResult<LayerNorm> = LayerNorm::configured(4, epsilon);
+        assert!(matches!(result, Err(MathError::InvalidParameter(_, _))), "Epsilon {} wasn't rejected", epsilon);
+        let config = LayerConfig::LayerNorm { size: 4, epsilon };
+        assert!(config.build::<f64>().is_err(), "Config with epsilon {} wasn't rejected", epsilon);
+    }
+}
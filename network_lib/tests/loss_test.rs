@@ -0,0 +1,143 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+mod common;
+
+use network_lib::loss::*;
+
+use matrix_lib::{errors::*, matrix::Matrix, matrix_functions::softmax};
+
+use common::*;
+
+/// compares analytical gradient with central finite differences
+fn assert_gradient(loss: &dyn Loss, expected: &Matrix, predicted: &Matrix, name: &str) -> MathResult<()> {
+    let gradient = loss.gradient(expected, predicted)?;
+    check_numerical_gradient(name, |predicted| loss.value(expected, predicted), predicted, &gradient)
+}
+
+fn regression_data() -> MathResult<(Matrix, Matrix)> {
+    let expected = Matrix::from_vector(&[
+        vec![1.0, -2.0, 0.5],
+        vec![0.0,  3.0, 1.5],
+    ])?;
+    let predicted = Matrix::from_vector(&[
+        vec![1.3, -0.5, 0.4],
+        vec![-0.2, 3.4, 2.0],
+    ])?;
+    Ok((expected, predicted))
+}
+
+fn classification_data() -> MathResult<(Matrix, Matrix)> {
+    let expected = Matrix::from_vector(&[
+        vec![1.0, 0.0],
+        vec![0.0, 0.0],
+        vec![0.0, 1.0],
+    ])?;
+    let predicted = Matrix::from_vector(&[
+        vec![0.7, 0.2],
+        vec![0.2, 0.3],
+        vec![0.1, 0.5],
+    ])?;
+    Ok((expected, predicted))
+}
+
+#[test]
+fn loss_mse_value() -> MathResult<()> {
+    let (expected, predicted) = regression_data()?;
+    let value = Mse.value(&expected, &predicted)?;
+    let reference = (0.09 + 2.25 + 0.01 + 0.04 + 0.16 + 0.25) / 6.0;
+    assert!((value - reference).abs() < 1e-12, "MSE value is {}", value);
+    Ok(())
+}
+
+#[test]
+fn loss_regression_gradients() -> MathResult<()> {
+    let (expected, predicted) = regression_data()?;
+    assert_gradient(&Mse, &expected, &predicted, "MSE")?;
+    assert_gradient(&Mae, &expected, &predicted, "MAE")?;
+    assert_gradient(&Huber::new(1.0)?, &expected, &predicted, "Huber")?;
+    Ok(())
+}
+
+#[test]
+fn loss_mae_exact_prediction() -> MathResult<()> {
+    let expected = Matrix::vector(&[1.0, -2.0])?;
+    let predicted = Matrix::vector(&[1.0, -1.0])?;
+    let gradient = Mae.gradient(&expected, &predicted)?;
+    assert_eq!(gradient, Matrix::vector(&[0.0, 0.5])?, "MAE gradient should be zero for exact predictions");
+    Ok(())
+}
+
+#[test]
+fn loss_huber_invalid_delta() {
+    for delta in [0.0, -1.0, f64::NAN] {
+        assert!(matches!(Huber::new(delta), Err(MathError::InvalidParameter(_, _))), "Delta {} wasn't rejected", delta);
+    }
+}
+
+#[test]
+fn loss_huber_value() -> MathResult<()> {
+    let expected = Matrix::vector(&[0.0, 0.0])?;
+    let predicted = Matrix::vector(&[0.5, 3.0])?;
+    // 0.5 * 0.5^2 and 1.0 * (3.0 - 0.5)
+    let value = Huber::new(1.0)?.value(&expected, &predicted)?;
+    assert!((value - (0.125 + 2.5) / 2.0).abs() < 1e-12, "Huber value is {}", value);
+    Ok(())
+}
+
+#[test]
+fn loss_classification_gradients() -> MathResult<()> {
+    let (expected, predicted) = classification_data()?;
+    assert_gradient(&BinaryCrossEntropy, &expected, &predicted, "Binary cross-entropy")?;
+    assert_gradient(&CategoricalCrossEntropy, &expected, &predicted, "Categorical cross-entropy")?;
+    assert_gradient(&SoftmaxCrossEntropy, &expected, &predicted, "Softmax cross-entropy")?;
+
+    let labels = Matrix::vector(&[1.0, -1.0, 1.0, -1.0])?;
+    let scores = Matrix::vector(&[0.3, 0.2, 1.5, -2.0])?;
+    assert_gradient(&Hinge, &labels, &scores, "Hinge")?;
+    Ok(())
+}
+
+#[test]
+fn loss_categorical_cross_entropy_value() -> MathResult<()> {
+    let (expected, predicted) = classification_data()?;
+    let value = CategoricalCrossEntropy.value(&expected, &predicted)?;
+    let reference = -(0.7f64.ln() + 0.5f64.ln()) / 2.0;
+    assert!((value - reference).abs() < 1e-12, "Cross-entropy value is {}", value);
+    Ok(())
+}
+
+#[test]
+fn loss_softmax_cross_entropy_is_stable() -> MathResult<()> {
+    let expected: Matrix = Matrix::vector(&[0.0, 1.0])?;
+    let logits = Matrix::vector(&[1000.0, 990.0])?;
+    let value = SoftmaxCrossEntropy.value(&expected, &logits)?;
+    assert!((value - 10.0).abs() < 1e-4, "Softmax cross-entropy value is {}", value);
+    let gradient = SoftmaxCrossEntropy.gradient(&expected, &logits)?;
+    assert!(gradient[0][0].is_finite() && gradient[1][0].is_finite(), "Gradient overflow: {:?}", gradient);
+    Ok(())
+}
+
+#[test]
+fn loss_softmax_columns() -> MathResult<()> {
+    let m = Matrix::from_vector(&[
+        vec![1.0, 0.0],
+        vec![2.0, 0.0],
+        vec![3.0, 0.0],
+    ])?;
+    let s = softmax(&m);
+    for j in 0..s.cols() {
+        let sum: f64 = (0..s.rows()).map(|i| s[i][j]).sum();
+        assert!((sum - 1.0).abs() < 1e-12, "Softmax column {} isn't normalized", j);
+    }
+    assert!((s[0][1] - 1.0 / 3.0).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn loss_dimensions_mismatch() {
+    let a: Matrix = Matrix::zero(2, 1);
+    let b = Matrix::zero(3, 1);
+    assert!(Mse.value(&a, &b).is_err());
+    assert!(SoftmaxCrossEntropy.gradient(&a, &b).is_err());
+}
@@ -0,0 +1,45 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+use network_lib::{
+    activation_layer::Activation, data_source::TrainDataSource, dense_layer::Dense,
+    layer::Layer, loss::Mse, network::FeedforwardNetwork, optimizer::Sgd,
+};
+
+use matrix_lib::{errors::*, matrix::Matrix};
+
+fn network() -> FeedforwardNetwork {
+    FeedforwardNetwork::new(vec![
+        Box::new(Dense::new(2, 3)),
+        Box::new(Activation::tanh()),
+        Box::new(Dense::new(4, 1)),
+    ])
+}
+
+#[test]
+fn network_error_has_layer_index() -> MathResult<()> {
+    let error = network().eval(&Matrix::vector(&[1.0, 2.0])?).unwrap_err();
+    assert!(matches!(&error, MathError::Layer(2, inner) if matches!(**inner, MathError::IncorrectMatricesDimensions(..))), "Unexpected error {:?}", error);
+    assert!(error.to_string().starts_with("Layer 2: "));
+    Ok(())
+}
+
+#[test]
+fn network_train_errors() -> MathResult<()> {
+    let mut empty = TrainDataSource::new();
+    let mut network = FeedforwardNetwork::new(vec![Box::new(Dense::new(1, 1))]);
+    assert!(matches!(network.train(1, &Mse, &mut Sgd::new(0.1), 1, &empty), Err(MathError::EmptyMatrix(_))));
+
+    empty.push(Matrix::vector(&[1e200])?, Matrix::vector(&[0.0])?);
+    let result = network.train(1, &Mse, &mut Sgd::new(0.1), 1, &empty);
+    assert!(matches!(result, Err(MathError::NotFinite(_))), "Diverged loss wasn't detected: {:?}", result);
+
+    let mut dense = Dense::new(1, 1);
+    *dense.state_mut()[0] = Matrix::vector(&[f64::NAN])?;
+    let mut network = FeedforwardNetwork::new(vec![Box::new(dense), Box::new(Activation::tanh())]);
+    let mut data = TrainDataSource::new();
+    data.push(Matrix::vector(&[1.0])?, Matrix::vector(&[0.0])?);
+    let result = network.train(1, &Mse, &mut Sgd::new(0.1), 1, &data);
+    assert!(matches!(&result, Err(MathError::Layer(0, inner)) if matches!(**inner, MathError::NotFinite(_))), "NaN output wasn't detected: {:?}", result);
+    Ok(())
+}
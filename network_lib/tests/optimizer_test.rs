@@ -0,0 +1,104 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+use network_lib::optimizer::*;
+
+use matrix_lib::{errors::MathResult, matrix::Matrix};
+
+/// minimizes f(x) = sum((x - target)^2) and returns the final point
+fn minimize(optimizer: &mut dyn Optimizer, steps: usize) -> MathResult<Matrix> {
+    let target = Matrix::vector(&[1.0, -2.0, 3.0])?;
+    let mut x = Matrix::zero(3, 1);
+    for _ in 0..steps {
+        let mut gradient = x.sub(&target)?;
+        gradient *= 2.0;
+        optimizer.next_step();
+        optimizer.update(0, &mut x, &gradient)?;
+    }
+    Ok(x)
+}
+
+fn assert_converged(optimizer: &mut dyn Optimizer, name: &str) -> MathResult<()> {
+    let x = minimize(optimizer, 2_000)?;
+    let expected = Matrix::vector(&[1.0, -2.0, 3.0])?;
+    let error = x.sub(&expected)?.powi(2).mean();
+    assert!(error < 1e-4, "{} didn't converge, x = {:?}", name, x);
+    Ok(())
+}
+
+#[test]
+fn optimizer_sgd_step() -> MathResult<()> {
+    let mut parameter = Matrix::vector(&[1.0, 2.0])?;
+    let gradient = Matrix::vector(&[0.5, -1.0])?;
+    let mut sgd = Sgd::new(0.1);
+    sgd.update(0, &mut parameter, &gradient)?;
+    assert_eq!(parameter, Matrix::vector(&[0.95, 2.1])?, "SGD step implemented incorrectly");
+    Ok(())
+}
+
+#[test]
+fn optimizer_momentum_accumulates_velocity() -> MathResult<()> {
+    let mut parameter = Matrix::vector(&[0.0])?;
+    let gradient = Matrix::vector(&[1.0])?;
+    let mut sgd = Sgd::momentum(0.1, 0.9);
+    sgd.update(0, &mut parameter, &gradient)?;
+    sgd.update(0, &mut parameter, &gradient)?;
+    // v1 = -0.1, v2 = 0.9 * v1 - 0.1 = -0.19
+    assert_eq!(parameter, Matrix::vector(&[-0.29])?, "Momentum implemented incorrectly");
+    Ok(())
+}
+
+#[test]
+fn optimizer_adam_first_step() -> MathResult<()> {
+    let mut parameter = Matrix::vector(&[1.0, 1.0])?;
+    let gradient = Matrix::vector(&[10.0, -0.01])?;
+    let mut adam = Adam::new(0.1, 0.9, 0.999);
+    adam.next_step();
+    adam.update(0, &mut parameter, &gradient)?;
+    // bias corrected first step has the magnitude of learning rate
+    let expected = Matrix::vector(&[0.9, 1.1])?;
+    assert!(parameter.sub(&expected)?.powi(2).mean() < 1e-10, "Adam step implemented incorrectly: {:?}", parameter);
+    Ok(())
+}
+
+#[test]
+fn optimizer_adamw_decays_weights() -> MathResult<()> {
+    let mut parameter = Matrix::vector(&[2.0])?;
+    let gradient = Matrix::vector(&[0.0])?;
+    let mut adamw = Adam::adamw(0.1, 0.9, 0.999, 0.5);
+    adamw.next_step();
+    adamw.update(0, &mut parameter, &gradient)?;
+    assert_eq!(parameter, Matrix::vector(&[1.9])?, "AdamW weight decay implemented incorrectly");
+    Ok(())
+}
+
+#[test]
+fn optimizer_state_is_per_parameter() -> MathResult<()> {
+    let gradient = Matrix::vector(&[1.0])?;
+    let mut first = Matrix::vector(&[0.0])?;
+    let mut second = Matrix::vector(&[0.0])?;
+    let mut sgd = Sgd::momentum(0.1, 0.9);
+    sgd.update(0, &mut first, &gradient)?;
+    sgd.update(1, &mut second, &gradient)?;
+    assert_eq!(first, second, "Velocity must not be shared between parameters");
+    Ok(())
+}
+
+#[test]
+fn optimizer_dimensions_mismatch() {
+    let mut parameter: Matrix = Matrix::zero(2, 1);
+    let gradient = Matrix::zero(3, 1);
+    assert!(Sgd::new(0.1).update(0, &mut parameter, &gradient).is_err());
+}
+
+#[test]
+fn optimizer_convergence() -> MathResult<()> {
+    assert_converged(&mut Sgd::new(0.05), "SGD")?;
+    assert_converged(&mut Sgd::momentum(0.05, 0.9), "Momentum")?;
+    assert_converged(&mut Sgd::nesterov(0.05, 0.9), "Nesterov")?;
+    assert_converged(&mut RmsProp::new(0.01, 0.9), "RMSProp")?;
+    assert_converged(&mut AdaGrad::new(0.5), "AdaGrad")?;
+    assert_converged(&mut Adam::new(0.05, 0.9, 0.999), "Adam")?;
+    assert_converged(&mut Adam::adamw(0.05, 0.9, 0.999, 1e-4), "AdamW")?;
+    Ok(())
+}
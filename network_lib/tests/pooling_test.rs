@@ -0,0 +1,141 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+mod common;
+
+use std::fs;
+
+use network_lib::{
+    conv_layer::Conv2D, dense_layer::Dense, image::*, layer::*, network::FeedforwardNetwork, pooling_layer::*,
+    serialization::LayerConfig,
+};
+
+use matrix_lib::{errors::*, matrix::Matrix};
+
+use common::*;
+
+const INPUT: ImageShape = ImageShape { channels: 2, height: 4, width: 5 };
+
+/// numerical input gradient of `sum(layer(input) * weights)`
+fn check_input_gradient(layer: &mut dyn Layer, input: &Matrix) -> MathResult<()> {
+    let output = layer.forward(input.clone())?;
+    let weights = Matrix::new(output.rows(), output.cols(), |i, j| ((i + 2 * j) % 5) as f64 - 2.0);
+    let gradient = layer.backward(&weights)?;
+    check_numerical_gradient("Input", |input| layer.eval(input)?.dot(&weights), input, &gradient)
+}
+
+#[test]
+fn max_pool_takes_window_maxima() -> MathResult<()> {
+    let shape = ImageShape::new(1, 4, 4);
+    let input = Matrix::new(16, 1, |i, _| i as f64);
+    let mut layer = MaxPool2D::new(shape, Window::square(2).with_stride(2))?;
+    assert_eq!(layer.output_shape(), ImageShape::new(1, 2, 2));
+    assert_eq!(layer.forward(input.clone())?, Matrix::vector(&[5.0, 7.0, 13.0, 15.0])?);
+
+    let gradient = layer.backward(&Matrix::vector(&[1.0, 2.0, 3.0, 4.0])?)?;
+    let expected = Matrix::new(16, 1, |i, _| match i { 5 => 1.0, 7 => 2.0, 13 => 3.0, 15 => 4.0, _ => 0.0 });
+    assert_eq!(gradient, expected, "Gradient should be routed to the maxima");
+
+    let negative = input.map(|x| -x - 1.0);
+    let padded = MaxPool2D::new(shape, Window::square(3).with_stride(2).with_padding(1))?;
+    assert_eq!(padded.eval(&negative)?, Matrix::vector(&[-1.0, -2.0, -5.0, -6.0])?, "Padding shouldn't win");
+    Ok(())
+}
+
+#[test]
+fn avg_pool_takes_window_means() -> MathResult<()> {
+    let shape = ImageShape::new(1, 4, 4);
+    let input = Matrix::new(16, 1, |i, _| i as f64);
+    let layer = AvgPool2D::new(shape, Window::square(2).with_stride(2))?;
+    assert_eq!(layer.eval(&input)?, Matrix::vector(&[2.5, 4.5, 10.5, 12.5])?);
+
+    let padded = AvgPool2D::new(shape, Window::square(2).with_stride(3).with_padding(1))?;
+    assert_eq!(padded.eval(&input)?, Matrix::vector(&[0.0, 1.25, 5.0, 12.5])?, "Padding should count as zeros");
+    Ok(())
+}
+
+#[test]
+fn global_average_pool_averages_channels() -> MathResult<()> {
+    let input = sample_input(INPUT.size(), 3);
+    let layer = GlobalAveragePool::new(INPUT)?;
+    let plane = INPUT.height * INPUT.width;
+    let expected = Matrix::new(2, 3, |c, b| (0..plane).map(|k| input[c * plane + k][b]).sum::<f64>() / plane as f64);
+    assert!(layer.eval(&input)?.approx_eq(&expected, 1e-12));
+    assert_eq!(layer.output_shape(), ImageShape::new(2, 1, 1));
+    Ok(())
+}
+
+#[test]
+fn pooling_backward_matches_numerical_gradient() -> MathResult<()> {
+    let input = sample_input(INPUT.size(), 3);
+    let window = Window::new(3, 2).with_stride(2).with_padding(1);
+    check_input_gradient(&mut MaxPool2D::new(INPUT, window)?, &input)?;
+    check_input_gradient(&mut AvgPool2D::new(INPUT, window)?, &input)?;
+    check_input_gradient(&mut AvgPool2D::new(INPUT, Window::square(2).with_dilation(2))?, &input)?;
+    check_input_gradient(&mut GlobalAveragePool::new(INPUT)?, &input)
+}
+
+#[test]
+fn pooling_reports_shape_errors() -> MathResult<()> {
+    let input = Matrix::zero(INPUT.size() + 1, 2);
+    let window = Window::square(2);
+    let layers: Vec<Box<dyn Layer>> = vec![
+        Box::new(MaxPool2D::new(INPUT, window)?),
+        Box::new(AvgPool2D::new(INPUT, window)?),
+        Box::new(GlobalAveragePool::new(INPUT)?),
+    ];
+    for mut layer in layers {
+        assert!(matches!(layer.eval(&input), Err(MathError::IncorrectMatricesDimensions(..))));
+        assert!(matches!(layer.forward(input.clone()), Err(MathError::IncorrectMatricesDimensions(..))));
+        layer.forward(sample_input(INPUT.size(), 3))?;
+        assert!(matches!(layer.backward(&Matrix::zero(1, 1)), Err(MathError::IncorrectMatricesDimensions(..))));
+    }
+    Ok(())
+}
+
+#[test]
+fn pooling_rejects_incorrect_window() {
+    for window in [Window::square(6), Window::square(0), Window::square(2).with_stride(0), Window::square(2).with_dilation(0)] {
+        assert!(matches!(MaxPool2D::<f64>::new(INPUT, window), Err(MathError::IncorrectMatricesDimensions(..))), "Window {:?} wasn't rejected", window);
+        assert!(matches!(AvgPool2D::<f64>::new(INPUT, window), Err(MathError::IncorrectMatricesDimensions(..))), "Window {:?} wasn't rejected", window);
+        let configs = [
+            LayerConfig::MaxPool2D { input_shape: INPUT, window },
+            LayerConfig::AvgPool2D { input_shape: INPUT, window },
+        ];
+        for config in configs {
+            assert!(config.build::<f64>().is_err(), "Config {:?} wasn't rejected", config);
+        }
+    }
+}
+
+#[test]
+fn global_average_pool_rejects_empty_plane() {
+    for input_shape in [ImageShape::new(2, 0, 5), ImageShape::new(2, 4, 0)] {
+        let result: MathResult<GlobalAveragePool> = GlobalAveragePool::new(input_shape);
+        assert!(matches!(result, Err(MathError::IncorrectMatricesDimensions(..))), "Shape {:?} wasn't rejected", input_shape);
+        let config = LayerConfig::GlobalAveragePool { input_shape };
+        assert!(config.build::<f64>().is_err(), "Config {:?} wasn't rejected", config);
+    }
+}
+
+#[test]
+fn pooling_network_round_trip() -> MathResult<()> {
+    let conv = Conv2D::new(INPUT, 3, Window::square(3).with_padding(1))?;
+    let max_pool = MaxPool2D::new(conv.output_shape(), Window::square(2).with_stride(2))?;
+    let avg_pool = AvgPool2D::new(max_pool.output_shape(), Window::square(2).with_padding(1))?;
+    let global_pool = GlobalAveragePool::new(avg_pool.output_shape())?;
+    let network: FeedforwardNetwork = FeedforwardNetwork::new(vec![
+        Box::new(conv),
+        Box::new(max_pool),
+        Box::new(avg_pool),
+        Box::new(global_pool),
+        Box::new(Dense::new(3, 2)),
+    ]);
+    let dir = std::env::temp_dir().join(format!("network_lib_pooling_{}", std::process::id()));
+    network.save(&dir).expect("Network should be saved");
+    let loaded = FeedforwardNetwork::<f64>::load(&dir).expect("Network should be loaded");
+    let input = sample_input(INPUT.size(), 3);
+    assert_eq!(loaded.eval(&input)?, network.eval(&input)?, "Pooling network wasn't restored");
+    _ = fs::remove_dir_all(&dir);
+    Ok(())
+}
@@ -0,0 +1,138 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+use std::{fs, path::PathBuf};
+
+use network_lib::{
+    activation_layer::Activation, dense_layer::Dense, dropout_layer::Dropout, network::FeedforwardNetwork,
+    serialization::*, softmax_layer::Softmax,
+};
+
+use matrix_lib::{errors::*, matrix::Matrix};
+
+fn temp_model_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("network_lib_{}_{}", name, std::process::id()));
+    _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn sample_network() -> FeedforwardNetwork {
+    FeedforwardNetwork::new(vec![
+        Box::new(Dense::new(3, 4)),
+        Box::new(Activation::leaky_relu(0.05)),
+        Box::new(Dropout::new(0.3).expect("Dropout rate is valid")),
+        Box::new(Dense::new(4, 2)),
+        Box::new(Softmax::new()),
+    ])
+}
+
+#[test]
+fn serialization_round_trip() -> MathResult<()> {
+    let dir = temp_model_dir("round_trip");
+    let network = sample_network();
+    network.save(&dir).expect("Network should be saved");
+    let loaded = FeedforwardNetwork::<f64>::load(&dir).expect("Network should be loaded");
+
+    let input = Matrix::from_vector(&[
+        vec![0.1, -2.0],
+        vec![1.5, 0.3],
+        vec![-0.7, 0.9],
+    ])?;
+    let expected = network.eval(&input)?;
+    let actual = loaded.eval(&input)?;
+    for i in 0..expected.rows() {
+        for j in 0..expected.cols() {
+            assert_eq!(expected[i][j].to_bits(), actual[i][j].to_bits(), "Loaded network isn't bit-identical");
+        }
+    }
+    _ = fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[test]
+fn serialization_unsupported_version() {
+    let dir = temp_model_dir("version");
+    sample_network().save(&dir).expect("Network should be saved");
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    let manifest = manifest.replace(&format!("\"version\": {}", FORMAT_VERSION), "\"version\": 999");
+    fs::write(&manifest_path, manifest).unwrap();
+
+    let result = FeedforwardNetwork::<f64>::load(&dir);
+    assert!(matches!(result, Err(SerializationError::UnsupportedVersion(999))), "Version mismatch wasn't detected");
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn serialization_shape_mismatch() {
+    let dir = temp_model_dir("shape");
+    FeedforwardNetwork::new(vec![Box::new(Dense::<f64>::new(2, 3))]).save(&dir).expect("Network should be saved");
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    let manifest = manifest.replace("\"input_size\": 2", "\"input_size\": 3");
+    fs::write(&manifest_path, manifest).unwrap();
+
+    let result = FeedforwardNetwork::<f64>::load(&dir);
+    assert!(matches!(result, Err(SerializationError::TensorShape(0, _, _))), "Shape mismatch wasn't detected");
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn serialization_huge_layer_rejected_before_allocation() {
+    let dir = temp_model_dir("huge");
+    FeedforwardNetwork::new(vec![Box::new(Dense::<f64>::new(2, 3))]).save(&dir).expect("Network should be saved");
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    let manifest = manifest.replace("\"input_size\": 2", "\"input_size\": 1000000000000");
+    fs::write(&manifest_path, manifest).unwrap();
+
+    let result = FeedforwardNetwork::<f64>::load(&dir);
+    assert!(matches!(result, Err(SerializationError::TensorShape(0, _, _))), "Huge layer wasn't rejected");
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn serialization_tensor_size_overflow() {
+    let dir = temp_model_dir("overflow");
+    FeedforwardNetwork::new(vec![Box::new(Dense::<f64>::new(2, 3))]).save(&dir).expect("Network should be saved");
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    let manifest = manifest.replacen("\"rows\": 3", &format!("\"rows\": {}", usize::MAX / 2), 1);
+    fs::write(&manifest_path, manifest).unwrap();
+
+    let result = FeedforwardNetwork::<f64>::load(&dir);
+    assert!(matches!(result, Err(SerializationError::Format(_))), "Overflowing tensor size wasn't detected");
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn serialization_invalid_dropout_rate() {
+    let dir = temp_model_dir("dropout_rate");
+    sample_network().save(&dir).expect("Network should be saved");
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    let manifest = manifest.replace("\"rate\": 0.3", "\"rate\": 1.5");
+    fs::write(&manifest_path, manifest).unwrap();
+
+    let result = FeedforwardNetwork::<f64>::load(&dir);
+    assert!(matches!(result, Err(SerializationError::Layer(2, MathError::InvalidParameter(_, _)))), "Invalid dropout rate wasn't rejected");
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn serialization_truncated_blob() {
+    let dir = temp_model_dir("truncated");
+    sample_network().save(&dir).expect("Network should be saved");
+    let blob_path = dir.join(TENSORS_FILE);
+    let blob = fs::read(&blob_path).unwrap();
+    fs::write(&blob_path, &blob[..blob.len() - 8]).unwrap();
+
+    assert!(matches!(FeedforwardNetwork::<f64>::load(&dir), Err(SerializationError::Format(_))), "Truncated blob wasn't detected");
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn serialization_missing_model() {
+    let dir = temp_model_dir("missing");
+    assert!(matches!(FeedforwardNetwork::<f64>::load(&dir), Err(SerializationError::Io(_))));
+}
@@ -0,0 +1,88 @@
+extern crate matrix_lib;
+extern crate network_lib;
+
+mod common;
+
+use network_lib::{
+    dense_layer::Dense, data_source::TrainDataSource, layer::Layer, loss::*,
+    network::FeedforwardNetwork, optimizer::*, softmax_layer::Softmax,
+};
+
+use matrix_lib::{errors::MathResult, matrix::Matrix, matrix_functions::softmax};
+
+use common::*;
+
+#[test]
+fn softmax_columns() -> MathResult<()> {
+    let m = Matrix::from_vector(&[
+        vec![1.0, 0.0],
+        vec![2.0, 0.0],
+        vec![3.0, 0.0],
+    ])?;
+    let s = Softmax::new().eval(&m)?;
+    for j in 0..s.cols() {
+        let sum: f64 = (0..s.rows()).map(|i| s[i][j]).sum();
+        assert!((sum - 1.0).abs() < 1e-12, "Softmax column {} isn't normalized", j);
+    }
+    assert!((s[0][1] - 1.0 / 3.0).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn softmax_large_inputs() -> MathResult<()> {
+    let m = Matrix::vector(&[1000.0, 1000.0])?;
+    let s = Softmax::new().eval(&m)?;
+    assert_eq!(s, Matrix::vector(&[0.5, 0.5])?, "Softmax overflow: {:?}", s);
+    Ok(())
+}
+
+#[test]
+fn softmax_backward_matches_numeric_jacobian() -> MathResult<()> {
+    let input = Matrix::from_vector(&[
+        vec![0.5, -1.0],
+        vec![1.5, 0.0],
+        vec![-0.3, 2.0],
+    ])?;
+    // gradient of L = sum(w * softmax(x)) is J^T * w
+    let weights = Matrix::from_vector(&[
+        vec![1.0, -2.0],
+        vec![0.5, 1.0],
+        vec![-1.0, 3.0],
+    ])?;
+    let objective = |x: &Matrix| -> MathResult<f64> {
+        let s = softmax(x);
+        Ok((0..s.rows()).map(|i| (0..s.cols()).map(|j| s[i][j] * weights[i][j]).sum::<f64>()).sum())
+    };
+    let mut layer = Softmax::new();
+    layer.forward(input.clone())?;
+    let gradient = layer.backward(&weights)?;
+    check_numerical_gradient("Softmax", objective, &input, &gradient)
+}
+
+#[test]
+fn softmax_classifier_training() -> MathResult<()> {
+    let mut data = TrainDataSource::new();
+    let samples = [
+        ([1.0, 0.1], 0),
+        ([0.9, -0.2], 0),
+        ([-1.0, 0.2], 1),
+        ([-0.8, -0.1], 1),
+        ([0.1, 1.0], 2),
+        ([-0.2, 0.9], 2),
+    ];
+    for (input, class) in samples.iter() {
+        let output: Vec<f64> = (0..3).map(|c| if c == *class { 1.0 } else { 0.0 }).collect();
+        data.push(Matrix::vector(input)?, Matrix::vector(&output)?);
+    }
+    let mut network = FeedforwardNetwork::new(vec![
+        Box::new(Dense::new(2, 3)),
+        Box::new(Softmax::new()),
+    ]);
+    network.train(500, &CategoricalCrossEntropy, &mut Adam::new(0.05, 0.9, 0.999), 6, &data)?;
+    for item in data.content() {
+        let output = network.eval(&item.input)?;
+        let predicted = (0..3).max_by(|a, b| output[*a][0].total_cmp(&output[*b][0])).unwrap();
+        assert_eq!(item.output[predicted][0], 1.0, "Wrong class for {:?}: {:?}", item.input, output);
+    }
+    Ok(())
+}